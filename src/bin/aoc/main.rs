@@ -0,0 +1,800 @@
+use aoc2022::animation::{Animation, AnimationConfig, Theme};
+use aoc2022::answers::{format_checks, Answers, Check, ANSWERS};
+use aoc2022::bench::{self, format_bench_table, BenchConfig};
+use aoc2022::day::{GENERATED, MAIN, TEST};
+use aoc2022::days;
+use aoc2022::days::day01::inventory::ElfInventory;
+use aoc2022::days::day01::stream::{format_top, read_top_k};
+use aoc2022::days::day02::game::Game;
+use aoc2022::days::day02::strategy::{best_mapping, format_mapping, format_standings, tournament};
+use aoc2022::input;
+use aoc2022::log::{self, Level};
+use aoc2022::parse::ParseError;
+use aoc2022::pool;
+use aoc2022::recorder::{RecordFormat, Recorder, RecorderConfig};
+use aoc2022::runner::{format_json, format_table, solve_all, Solver};
+use std::env;
+use std::fs;
+use std::process::exit;
+use std::time::Duration;
+
+const USAGE: &str = "usage: aoc [all | DAY[-DAY][,DAY]...]... [--test | --input PATH|- | --generate SIZE [--seed N]] [--check] [--format table|json] [--threads N] [-v... | --log LEVEL]
+       aoc DAY --generate SIZE [--seed N] --emit
+       aoc 1 [--test | --input PATH|- | --generate SIZE [--seed N]] --top K
+       aoc 1 [--test | --input PATH|- | --generate SIZE [--seed N]] --report
+       aoc 2 [--test | --input PATH|- | --generate SIZE [--seed N]] --best-mapping
+       aoc 2 --tournament PATH,PATH[,PATH]...
+       aoc [DAYS]... [--test | --input PATH|-] --bench [--samples N] [--save PATH] [--baseline PATH]
+       aoc DAY [--test | --input PATH|-] --animate [--play] [--delay MS] [--window ROWSxCOLUMNS] [--theme NAME]
+       aoc DAY [--test | --input PATH|-] --record PATH [--image gif|ppm|pgm] [--every N] [--limit N] [--scale N] [--delay MS]";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Table,
+    Json,
+}
+
+#[derive(Debug, PartialEq)]
+struct Args {
+    days: Vec<u8>,
+    input: Option<String>,
+    test: bool,
+    generate: Option<usize>,
+    seed: u64,
+    emit: bool,
+    check: bool,
+    format: Format,
+    bench: bool,
+    samples: usize,
+    save: Option<String>,
+    baseline: Option<String>,
+    animate: bool,
+    animation: AnimationConfig,
+    record: Option<String>,
+    recorder: RecorderConfig,
+    top: Option<usize>,
+    report: bool,
+    best_mapping: bool,
+    tournament: Option<Vec<String>>,
+    log: Level,
+    threads: usize,
+}
+
+fn parse_args<I: Iterator<Item = String>>(mut args: I) -> Result<Args, String> {
+    let mut days = Vec::new();
+    let mut input = None;
+    let mut test = false;
+    let mut generate = None;
+    let mut seed = None;
+    let mut emit = false;
+    let mut check = false;
+    let mut format = Format::Table;
+    let mut bench = false;
+    let mut samples = BenchConfig::default().samples;
+    let mut save = None;
+    let mut baseline = None;
+    let mut animate = false;
+    let mut animation = AnimationConfig::default();
+    let mut animation_options = false;
+    let mut delay_set = false;
+    let mut record = None;
+    let mut recorder = RecorderConfig::default();
+    let mut image = None;
+    let mut recorder_options = false;
+    let mut top = None;
+    let mut report = false;
+    let mut best_mapping = false;
+    let mut tournament = None;
+    let mut verbosity = 0;
+    let mut log = None;
+    let mut threads = 0;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" | "-i" => {
+                input = Some(args.next().ok_or("--input needs a path or -")?);
+            }
+            "--test" | "-t" => test = true,
+            "--generate" | "-g" => {
+                generate = Some(
+                    args.next()
+                        .and_then(|n| n.parse().ok())
+                        .filter(|n| *n > 0)
+                        .ok_or("--generate needs a positive size")?,
+                );
+            }
+            "--seed" => {
+                seed = Some(
+                    args.next()
+                        .and_then(|n| n.parse().ok())
+                        .ok_or("--seed needs a number")?,
+                );
+            }
+            "--emit" => emit = true,
+            "--check" | "-c" => check = true,
+            "--format" | "-f" => {
+                format = match args.next().as_deref() {
+                    Some("table") => Format::Table,
+                    Some("json") => Format::Json,
+                    _ => return Err("--format needs table or json".into()),
+                }
+            }
+            "--bench" | "-b" => bench = true,
+            "--samples" => {
+                samples = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|n| *n > 0)
+                    .ok_or("--samples needs a positive number")?;
+            }
+            "--save" => save = Some(args.next().ok_or("--save needs a path")?),
+            "--baseline" => baseline = Some(args.next().ok_or("--baseline needs a path")?),
+            "--animate" | "-a" => animate = true,
+            "--play" => {
+                animation.paused = false;
+                animation_options = true;
+            }
+            "--delay" => {
+                animation.delay = args
+                    .next()
+                    .and_then(|ms| ms.parse().ok())
+                    .map(Duration::from_millis)
+                    .ok_or("--delay needs a number of milliseconds")?;
+                recorder.delay = animation.delay;
+                delay_set = true;
+            }
+            "--window" => {
+                animation.window = Some(
+                    args.next()
+                        .as_deref()
+                        .and_then(|w| w.split_once('x'))
+                        .and_then(|(r, c)| Some((r.parse().ok()?, c.parse().ok()?)))
+                        .filter(|(r, c)| *r > 0 && *c > 0)
+                        .ok_or("--window needs a size like 40x80")?,
+                );
+                animation_options = true;
+            }
+            "--theme" => {
+                animation.theme = args
+                    .next()
+                    .and_then(|t| Theme::named(&t))
+                    .ok_or_else(|| format!("--theme needs one of {}", Theme::NAMES.join(", ")))?;
+                animation_options = true;
+            }
+            "--record" | "-r" => record = Some(args.next().ok_or("--record needs a path")?),
+            "--image" => {
+                image = Some(
+                    args.next()
+                        .and_then(|f| RecordFormat::named(&f))
+                        .ok_or("--image needs gif, ppm or pgm")?,
+                );
+                recorder_options = true;
+            }
+            "--every" | "--limit" | "--scale" => {
+                let n = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|n| *n > 0)
+                    .ok_or_else(|| format!("{} needs a positive number", arg))?;
+                match arg.as_str() {
+                    "--every" => recorder.every = n,
+                    "--limit" => recorder.limit = Some(n),
+                    _ => recorder.scale = n,
+                }
+                recorder_options = true;
+            }
+            "--top" => {
+                top = Some(
+                    args.next()
+                        .and_then(|k| k.parse().ok())
+                        .filter(|k| *k > 0)
+                        .ok_or("--top needs a positive number")?,
+                );
+            }
+            "--report" => report = true,
+            "--best-mapping" => best_mapping = true,
+            "--tournament" => {
+                tournament = Some(
+                    args.next()
+                        .map(|paths| {
+                            paths
+                                .split(',')
+                                .filter(|p| !p.is_empty())
+                                .map(String::from)
+                                .collect::<Vec<_>>()
+                        })
+                        .filter(|paths| paths.len() >= 2)
+                        .ok_or("--tournament needs at least two comma separated guide paths")?,
+                );
+            }
+            "--threads" | "-j" => {
+                threads = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|n| *n > 0)
+                    .ok_or("--threads needs a positive number")?;
+            }
+            "--log" => {
+                log =
+                    Some(args.next().and_then(|l| Level::named(&l)).ok_or_else(|| {
+                        format!("--log needs one of {}", Level::NAMES.join(", "))
+                    })?);
+            }
+            v if v.len() > 1 && v.starts_with('-') && v[1..].chars().all(|c| c == 'v') => {
+                verbosity += v.len() - 1;
+            }
+            "all" => days.extend(all_days()),
+            option if option.starts_with('-') => {
+                return Err(format!("unknown option '{}'", option));
+            }
+            list => {
+                for d in list.split(',').filter(|d| !d.is_empty()) {
+                    days.extend(parse_days(d)?);
+                }
+            }
+        }
+    }
+
+    if days.is_empty() {
+        days.extend(all_days());
+    }
+    let mut seen = Vec::new();
+    days.retain(|day| {
+        let first = !seen.contains(day);
+        seen.push(*day);
+        first
+    });
+    if input.is_some() && test {
+        return Err("--input and --test can not be used together".into());
+    }
+    if input.is_some() && days.len() != 1 {
+        return Err("--input can only be used when running a single day".into());
+    }
+    if generate.is_some() && (input.is_some() || test) {
+        return Err("--generate can not be used with --input or --test".into());
+    }
+    if generate.is_none() && (seed.is_some() || emit) {
+        return Err("--seed and --emit can only be used with --generate".into());
+    }
+    if emit && days.len() != 1 {
+        return Err("--emit can only be used for a single day".into());
+    }
+    if (input.is_some() || generate.is_some()) && check {
+        return Err("--check only knows the answers of the bundled inputs".into());
+    }
+    if bench && check {
+        return Err("--bench and --check can not be used together".into());
+    }
+    if bench && format == Format::Json {
+        return Err("--bench only prints tables".into());
+    }
+    let simulate = animate || record.is_some();
+    if animate && record.is_some() {
+        return Err("--animate and --record can not be used together".into());
+    }
+    if simulate && (bench || check || format == Format::Json) {
+        return Err(
+            "--animate and --record can not be used with --bench, --check or --format json".into(),
+        );
+    }
+    if simulate && !matches!(&days[..], [day] if days::get(*day).unwrap().animated()) {
+        let animated: Vec<String> = days::DAYS
+            .iter()
+            .filter(|s| s.animated())
+            .map(|s| s.day().to_string())
+            .collect();
+        return Err(format!(
+            "--animate and --record need a single day out of {}",
+            animated.join(", ")
+        ));
+    }
+    if !animate && animation_options {
+        return Err("--play, --window and --theme can only be used with --animate".into());
+    }
+    if record.is_none() && recorder_options {
+        return Err("--image, --every, --limit and --scale can only be used with --record".into());
+    }
+    if !simulate && delay_set {
+        return Err("--delay can only be used with --animate or --record".into());
+    }
+    if let Some(path) = &record {
+        recorder.format = image.unwrap_or(RecordFormat::from_path(path));
+    }
+    if !bench && (save.is_some() || baseline.is_some()) {
+        return Err("--save and --baseline can only be used with --bench".into());
+    }
+    if (top.is_some() || report) && days != [1] {
+        return Err("--top and --report can only be used for day 1".into());
+    }
+    if top.is_some() && report {
+        return Err("--top and --report can not be used together".into());
+    }
+    if (best_mapping || tournament.is_some()) && days != [2] {
+        return Err("--best-mapping and --tournament can only be used for day 2".into());
+    }
+    if best_mapping && tournament.is_some() {
+        return Err("--best-mapping and --tournament can not be used together".into());
+    }
+    if tournament.is_some() && (input.is_some() || test || generate.is_some()) {
+        return Err("--tournament reads its own guides, not --input, --test or --generate".into());
+    }
+    if (top.is_some() || report || best_mapping || tournament.is_some())
+        && (emit || check || bench || simulate || format == Format::Json)
+    {
+        return Err("--top, --report, --best-mapping and --tournament can not be used with --emit, --check, --bench, --animate, --record or --format json".into());
+    }
+
+    Ok(Args {
+        days,
+        input,
+        test,
+        generate,
+        seed: seed.unwrap_or(0),
+        emit,
+        check,
+        format,
+        bench,
+        samples,
+        save,
+        baseline,
+        animate,
+        animation,
+        record,
+        recorder,
+        top,
+        report,
+        best_mapping,
+        tournament,
+        log: log.unwrap_or(Level::verbosity(verbosity)),
+        threads,
+    })
+}
+
+fn parse_days(range: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |d: &str| {
+        let day: u8 = d.parse().map_err(|_| format!("invalid day '{}'", d))?;
+        if days::get(day).is_some() {
+            Ok(day)
+        } else {
+            Err(format!("day {} is not solved", day))
+        }
+    };
+
+    match range.split_once('-') {
+        Some((from, to)) => {
+            let (from, to) = (parse_day(from)?, parse_day(to)?);
+            if from > to {
+                return Err(format!("day range '{}' runs backwards", range));
+            }
+            Ok(all_days().filter(|d| (from..=to).contains(d)).collect())
+        }
+        None => Ok(vec![parse_day(range)?]),
+    }
+}
+
+fn all_days() -> impl Iterator<Item = u8> {
+    days::DAYS.iter().map(|s| s.day())
+}
+
+fn main() {
+    if env::args().skip(1).any(|a| a == "--help" || a == "-h") {
+        println!("{}", USAGE);
+        return;
+    }
+    let args = parse_args(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        exit(2);
+    });
+    log::set_level(args.log);
+    pool::set_threads(args.threads);
+
+    let input_name = match (args.test, args.generate) {
+        (true, _) => TEST,
+        (_, Some(_)) => GENERATED,
+        _ => MAIN,
+    };
+    if let Some(k) = args.top {
+        if run_top(&args, k, input_name) {
+            exit(1);
+        }
+        return;
+    }
+    if let Some(paths) = &args.tournament {
+        if run_tournament(paths) {
+            exit(1);
+        }
+        return;
+    }
+    let inputs = args.days.iter().map(|day| {
+        let solver = days::get(*day).unwrap();
+        let file = match (&args.input, args.generate) {
+            (Some(path), _) => input::read(path).unwrap_or_else(|e| {
+                eprintln!("could not read puzzle input {}", e);
+                exit(1);
+            }),
+            (_, Some(size)) => solver.generate(args.seed, size),
+            _ => solver.input(input_name).unwrap().to_string(),
+        };
+        (solver, file)
+    });
+
+    let failed = if args.emit {
+        inputs.for_each(|(_, file)| print!("{}", file));
+        false
+    } else if args.record.is_some() {
+        run_recorder(&args, inputs, input_name)
+    } else if args.animate {
+        run_animation(&args, inputs, input_name)
+    } else if args.bench {
+        run_bench(&args, inputs, input_name)
+    } else if args.report {
+        run_report(inputs)
+    } else if args.best_mapping {
+        run_best_mapping(inputs)
+    } else {
+        run(&args, inputs, input_name)
+    };
+    if failed {
+        exit(1);
+    }
+}
+
+fn run<'a, I>(args: &Args, inputs: I, input_name: &str) -> bool
+where
+    I: Iterator<Item = (&'a dyn Solver, String)>,
+{
+    let inputs: Vec<(&dyn Solver, String)> = inputs.collect();
+    let mut results = Vec::new();
+    let mut failed = false;
+    for ((solver, _), result) in inputs.iter().zip(solve_all(&inputs, input_name)) {
+        match result {
+            Ok(result) => results.push(result),
+            Err(e) => {
+                report_parse_error(solver.day(), e);
+                failed = true;
+            }
+        }
+    }
+
+    match args.format {
+        Format::Table if !results.is_empty() => print!("{}", format_table(&results)),
+        Format::Table => {}
+        Format::Json => print!("{}", format_json(&results)),
+    }
+    if args.check {
+        let answers = Answers::parse(ANSWERS).expect("bundled answers are valid");
+        let checks: Vec<_> = results
+            .iter()
+            .flat_map(|r| answers.check(r, input_name))
+            .collect();
+        // Keep stdout parseable when it carries JSON.
+        match args.format {
+            Format::Table => print!("\n{}", format_checks(&checks)),
+            Format::Json => eprint!("{}", format_checks(&checks)),
+        }
+        failed |= checks.iter().any(|c| matches!(c.check, Check::Fail { .. }));
+    }
+    failed
+}
+
+fn run_bench<'a, I>(args: &Args, inputs: I, input_name: &str) -> bool
+where
+    I: Iterator<Item = (&'a dyn Solver, String)>,
+{
+    let baseline = args.baseline.as_ref().map(|path| {
+        let file = input::read(path).unwrap_or_else(|e| {
+            eprintln!("could not read baseline {}", e);
+            exit(1);
+        });
+        bench::load(&file).unwrap_or_else(|e| {
+            eprintln!("could not parse baseline {}, {}", path, e);
+            exit(1);
+        })
+    });
+    let config = BenchConfig {
+        samples: args.samples,
+        ..BenchConfig::default()
+    };
+
+    let mut benches = Vec::new();
+    let mut failed = false;
+    for (solver, file) in inputs {
+        match solver.bench(&file, input_name, &config) {
+            Ok(b) => benches.push(b),
+            Err(e) => {
+                report_parse_error(solver.day(), e);
+                failed = true;
+            }
+        }
+    }
+
+    print!("{}", format_bench_table(&benches, baseline.as_ref()));
+    if let Some(path) = &args.save {
+        if let Err(e) = fs::write(path, bench::save(&benches)) {
+            eprintln!("could not save benchmark to {}: {}", path, e);
+            failed = true;
+        }
+    }
+    failed
+}
+
+fn run_animation<'a, I>(args: &Args, inputs: I, input_name: &str) -> bool
+where
+    I: Iterator<Item = (&'a dyn Solver, String)>,
+{
+    let mut animation = Animation::terminal(args.animation.clone());
+    let mut failed = false;
+    for (solver, file) in inputs {
+        if let Err(e) = solver.animate(&file, input_name, &mut animation) {
+            report_parse_error(solver.day(), e);
+            failed = true;
+        }
+    }
+    failed
+}
+
+fn run_recorder<'a, I>(args: &Args, inputs: I, input_name: &str) -> bool
+where
+    I: Iterator<Item = (&'a dyn Solver, String)>,
+{
+    let path = args.record.as_ref().unwrap();
+    let mut recorder = Recorder::new(path, args.recorder.clone()).unwrap_or_else(|e| {
+        eprintln!("could not record to {}: {}", path, e);
+        exit(1);
+    });
+    for (solver, file) in inputs {
+        if let Err(e) = solver.animate(&file, input_name, &mut recorder) {
+            report_parse_error(solver.day(), e);
+            return true;
+        }
+    }
+    match recorder.finish() {
+        Ok(frames) => {
+            println!("recorded {} frames to {}", frames, path);
+            false
+        }
+        Err(e) => {
+            eprintln!("could not record to {}: {}", path, e);
+            true
+        }
+    }
+}
+
+/// Ranks the elves of day 1 while streaming its input, so that inventories too large to hold in
+/// memory work too.
+fn run_top(args: &Args, k: usize, input_name: &str) -> bool {
+    let solver = days::get(1).unwrap();
+    let top = match (&args.input, args.generate) {
+        (Some(path), _) => match input::open(path) {
+            Ok(reader) => read_top_k(reader, k),
+            Err(e) => {
+                eprintln!("could not read puzzle input {}", e);
+                return true;
+            }
+        },
+        (_, Some(size)) => read_top_k(solver.generate(args.seed, size).as_bytes(), k),
+        _ => read_top_k(solver.input(input_name).unwrap().as_bytes(), k),
+    };
+    match top {
+        Ok(elves) => {
+            print!("{}", format_top(&elves));
+            false
+        }
+        Err(e) => {
+            eprintln!("day 01: could not read puzzle input, {}", e);
+            true
+        }
+    }
+}
+
+/// Prints the summary report of the elf inventory of day 1.
+fn run_report<'a, I>(inputs: I) -> bool
+where
+    I: Iterator<Item = (&'a dyn Solver, String)>,
+{
+    let mut failed = false;
+    for (solver, file) in inputs {
+        match ElfInventory::parse(&file) {
+            Ok(inventory) => print!("{}", inventory),
+            Err(e) => {
+                report_parse_error(solver.day(), e);
+                failed = true;
+            }
+        }
+    }
+    failed
+}
+
+/// Finds the moves the letters of the second column of day 2 could stand for that score the most.
+fn run_best_mapping<'a, I>(inputs: I) -> bool
+where
+    I: Iterator<Item = (&'a dyn Solver, String)>,
+{
+    let game = Game::rock_paper_scissors();
+    let mut failed = false;
+    for (solver, file) in inputs {
+        match game.parse_guide(&file) {
+            Ok(guide) => print!("{}", format_mapping(game, &best_mapping(game, &guide))),
+            Err(e) => {
+                report_parse_error(solver.day(), e);
+                failed = true;
+            }
+        }
+    }
+    failed
+}
+
+/// Plays the day 2 strategy guides at `paths` against each other, each known by its path.
+fn run_tournament(paths: &[String]) -> bool {
+    let game = Game::rock_paper_scissors();
+    let mut guides = Vec::new();
+    for path in paths {
+        let guide = input::read(path)
+            .map_err(|e| e.to_string())
+            .and_then(|file| {
+                game.parse_guide(&file)
+                    .map_err(|e| format!("{}: {}", path, e))
+            });
+        match guide {
+            Ok(guide) => guides.push((path.as_str(), guide)),
+            Err(e) => {
+                eprintln!("could not read strategy guide {}", e);
+                return true;
+            }
+        }
+    }
+    print!("{}", format_standings(&tournament(game, &guides)));
+    false
+}
+
+fn report_parse_error(day: u8, e: ParseError) {
+    eprintln!("day {:02}: could not parse puzzle input, {}", day, e);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(a: &str) -> Result<Args, String> {
+        parse_args(a.split_ascii_whitespace().map(String::from))
+    }
+
+    #[test]
+    pub fn test_parse_args() {
+        assert_eq!(args("").unwrap().days, all_days().collect::<Vec<u8>>());
+        assert_eq!(args("all").unwrap().days, args("1-25").unwrap().days);
+        assert!(args("3 --test").unwrap().test);
+        assert!(args("--test --check").unwrap().check);
+        assert_eq!(args("1 --format json").unwrap().format, Format::Json);
+        assert_eq!(args("19 -v").unwrap().log, Level::Info);
+        assert_eq!(args("--threads 4").unwrap().threads, 4);
+        assert_eq!(args("19 -vv -v").unwrap().log, Level::Trace);
+        assert_eq!(args("19 -vvv --log info").unwrap().log, Level::Info);
+        let a = args("22 --animate --play --delay 50 --window 20x60 --theme plain").unwrap();
+        assert!(a.animate && !a.animation.paused);
+        assert_eq!(a.animation.delay, Duration::from_millis(50));
+        assert_eq!(a.animation.window, Some((20, 60)));
+        let a = args("14 --record sand.gif --every 10 --limit 50 --delay 20").unwrap();
+        assert_eq!(a.record, Some("sand.gif".into()));
+        assert_eq!(
+            a.recorder,
+            RecorderConfig {
+                format: RecordFormat::Gif,
+                every: 10,
+                limit: Some(50),
+                delay: Duration::from_millis(20),
+                ..RecorderConfig::default()
+            }
+        );
+        assert_eq!(
+            args("9 --record out").unwrap().recorder.format,
+            RecordFormat::Ppm
+        );
+        let a = args("9 --record out --image pgm --scale 2").unwrap();
+        assert_eq!(
+            (a.recorder.format, a.recorder.scale),
+            (RecordFormat::Pgm, 2)
+        );
+        let a = args("3 --bench --samples 5 --save new --baseline old").unwrap();
+        assert!(a.bench);
+        assert_eq!(a.samples, 5);
+        assert_eq!(
+            (a.save.unwrap(), a.baseline.unwrap()),
+            ("new".into(), "old".into())
+        );
+        let a = args("9 --generate 100 --seed 7 --emit").unwrap();
+        assert_eq!((a.generate, a.seed, a.emit), (Some(100), 7, true));
+        assert_eq!(args("all -g 10").unwrap().seed, 0);
+        assert_eq!(args("5").unwrap().days, [5]);
+        assert_eq!(args("01 3,4").unwrap().days, [1, 3, 4]);
+        assert_eq!(args("15-19").unwrap().days, [15, 16, 18, 19]);
+        assert_eq!(args("3 1-3 3,2").unwrap().days, [3, 1, 2]);
+        assert_eq!(args("all 5").unwrap().days, args("all").unwrap().days);
+        assert_eq!(args("1 --input - --top 3").unwrap().top, Some(3));
+        assert!(args("1 --test --report").unwrap().report);
+        assert!(args("2 --test --best-mapping").unwrap().best_mapping);
+        assert_eq!(
+            args("2 --tournament a,b,").unwrap().tournament,
+            Some(vec!["a".into(), "b".into()])
+        );
+        assert_eq!(
+            args("2 --input -").unwrap(),
+            Args {
+                days: vec![2],
+                input: Some("-".into()),
+                test: false,
+                generate: None,
+                seed: 0,
+                emit: false,
+                check: false,
+                format: Format::Table,
+                bench: false,
+                samples: BenchConfig::default().samples,
+                save: None,
+                baseline: None,
+                animate: false,
+                animation: AnimationConfig::default(),
+                record: None,
+                recorder: RecorderConfig::default(),
+                top: None,
+                report: false,
+                best_mapping: false,
+                tournament: None,
+                log: Level::Off,
+                threads: 0,
+            }
+        );
+    }
+
+    #[test]
+    pub fn test_parse_args_errors() {
+        assert_eq!(args("17").unwrap_err(), "day 17 is not solved");
+        assert_eq!(args("x").unwrap_err(), "invalid day 'x'");
+        assert_eq!(args("1 --chek").unwrap_err(), "unknown option '--chek'");
+        assert_eq!(args("-x").unwrap_err(), "unknown option '-x'");
+        assert_eq!(
+            args("20-15").unwrap_err(),
+            "day range '20-15' runs backwards"
+        );
+        assert!(args("1 2 --input -").is_err());
+        assert!(args("1 --input").is_err());
+        assert!(args("1 --test --input -").is_err());
+        assert!(args("1 --check --input -").is_err());
+        assert!(args("1 --generate 0").is_err());
+        assert!(args("1 --generate 5 --test").is_err());
+        assert!(args("1 --generate 5 --check").is_err());
+        assert!(args("1 --seed 5").is_err());
+        assert!(args("1 2 --generate 5 --emit").is_err());
+        assert!(args("1 --bench --check").is_err());
+        assert!(args("1 --save out").is_err());
+        assert!(args("1 --bench --samples 0").is_err());
+        assert!(args("1 --format xml").is_err());
+        assert!(args("1 --bench --format json").is_err());
+        assert!(args("1 --animate").is_err());
+        assert!(args("22 23 --animate").is_err());
+        assert!(args("23 --animate --check").is_err());
+        assert!(args("23 --animate --window 20").is_err());
+        assert!(args("23 --animate --theme neon").is_err());
+        assert!(args("23 --play").is_err());
+        assert!(args("19 --log loud").is_err());
+        assert!(args("--threads 0").is_err());
+        assert!(args("23 --delay 10").is_err());
+        assert!(args("23 --every 10").is_err());
+        assert!(args("23 --record out --animate").is_err());
+        assert!(args("1 --record out").is_err());
+        assert!(args("9 --record out --image png").is_err());
+        assert!(args("9 --record out --scale 0").is_err());
+        assert!(args("1 --top 0").is_err());
+        assert!(args("1 2 --top 3").is_err());
+        assert!(args("2 --top 3").is_err());
+        assert!(args("1 --top 3 --check").is_err());
+        assert!(args("2 --report").is_err());
+        assert!(args("1 --report --top 3").is_err());
+        assert!(args("1 --report --format json").is_err());
+        assert!(args("1 --best-mapping").is_err());
+        assert!(args("2 --best-mapping --bench").is_err());
+        assert!(args("2 --tournament a").is_err());
+        assert!(args("2 --tournament a,b --test").is_err());
+        assert!(args("2 --tournament a,b --best-mapping").is_err());
+    }
+}
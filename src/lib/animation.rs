@@ -0,0 +1,383 @@
+use colored::{Color, ColoredString, Colorize};
+use std::io::{stdin, stdout, BufRead, Write};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+pub const CONTROLS: &str = "enter/s step, p play/pause, + faster, - slower, q quit";
+
+/// What a simulation shows at one position, the theme decides how it looks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cell {
+    Blank,
+    Empty,
+    Wall,
+    Actor,
+    Marker(char),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub empty: ColoredString,
+    pub wall: ColoredString,
+    pub actor: ColoredString,
+    pub marker: Option<Color>,
+}
+
+impl Theme {
+    pub const NAMES: [&'static str; 3] = ["plain", "classic", "contrast"];
+
+    pub fn named(name: &str) -> Option<Theme> {
+        match name {
+            "plain" => Some(Theme {
+                empty: ".".normal(),
+                wall: "#".normal(),
+                actor: "@".normal(),
+                marker: None,
+            }),
+            "classic" => Some(Theme {
+                empty: ".".bright_black(),
+                wall: "#".white(),
+                actor: "@".bright_red().bold(),
+                marker: Some(Color::Cyan),
+            }),
+            "contrast" => Some(Theme {
+                empty: " ".on_black(),
+                wall: " ".on_white(),
+                actor: "@".black().on_yellow(),
+                marker: Some(Color::BrightBlue),
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn paint(&self, cell: Cell) -> ColoredString {
+        match cell {
+            Cell::Blank => " ".normal(),
+            Cell::Empty => self.empty.clone(),
+            Cell::Wall => self.wall.clone(),
+            Cell::Actor => self.actor.clone(),
+            Cell::Marker(c) => match self.marker {
+                Some(color) => c.to_string().color(color),
+                None => c.to_string().normal(),
+            },
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::named("classic").unwrap()
+    }
+}
+
+/// Inclusive rectangle of `(y, x)` positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Viewport {
+    pub y_min: i32,
+    pub x_min: i32,
+    pub y_max: i32,
+    pub x_max: i32,
+}
+
+impl Viewport {
+    pub fn new(y_min: i32, x_min: i32, y_max: i32, x_max: i32) -> Viewport {
+        Viewport {
+            y_min,
+            x_min,
+            y_max,
+            x_max,
+        }
+    }
+
+    pub fn bounding<I: IntoIterator<Item = (i32, i32)>>(points: I) -> Option<Viewport> {
+        points.into_iter().fold(None, |view, (y, x)| {
+            Some(match view {
+                None => Viewport::new(y, x, y, x),
+                Some(v) => Viewport::new(
+                    v.y_min.min(y),
+                    v.x_min.min(x),
+                    v.y_max.max(y),
+                    v.x_max.max(x),
+                ),
+            })
+        })
+    }
+
+    pub fn center(&self) -> (i32, i32) {
+        ((self.y_min + self.y_max) / 2, (self.x_min + self.x_max) / 2)
+    }
+
+    /// A window of at most `rows` by `columns` centred on `focus`, shifted to stay inside this viewport.
+    pub fn window(&self, focus: (i32, i32), (rows, columns): (i32, i32)) -> Viewport {
+        let axis = |min: i32, max: i32, f: i32, len: i32| {
+            if max - min < len {
+                return (min, max);
+            }
+            let start = (f - len / 2).clamp(min, max - len + 1);
+            (start, start + len - 1)
+        };
+        let (y_min, y_max) = axis(self.y_min, self.y_max, focus.0, rows);
+        let (x_min, x_max) = axis(self.x_min, self.x_max, focus.1, columns);
+        Viewport::new(y_min, x_min, y_max, x_max)
+    }
+}
+
+pub fn render<F: FnMut(i32, i32) -> Cell>(view: &Viewport, theme: &Theme, mut cell: F) -> String {
+    let mut frame = String::new();
+    for y in view.y_min..=view.y_max {
+        for x in view.x_min..=view.x_max {
+            frame.push_str(&theme.paint(cell(y, x)).to_string());
+        }
+        frame.push('\n');
+    }
+    frame
+}
+
+/// Receives the frames of a simulation, either to show or to record them.
+pub trait Frames {
+    /// Takes one frame of `bounds` with `focus` as the position of most interest.
+    /// Returns false once no more frames are wanted.
+    fn frame(
+        &mut self,
+        bounds: &Viewport,
+        focus: (i32, i32),
+        caption: &str,
+        cell: &mut dyn FnMut(i32, i32) -> Cell,
+    ) -> bool;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Step,
+    Toggle,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Command {
+    pub fn parse(line: &str) -> Option<Command> {
+        match line.trim() {
+            "" | "s" => Some(Command::Step),
+            "p" => Some(Command::Toggle),
+            "+" => Some(Command::Faster),
+            "-" => Some(Command::Slower),
+            "q" => Some(Command::Quit),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnimationConfig {
+    pub theme: Theme,
+    pub delay: Duration,
+    /// Largest part of the simulation shown at once as rows and columns, everything when unset.
+    pub window: Option<(i32, i32)>,
+    pub paused: bool,
+}
+
+impl Default for AnimationConfig {
+    fn default() -> Self {
+        AnimationConfig {
+            theme: Theme::default(),
+            delay: Duration::from_millis(400),
+            window: None,
+            paused: true,
+        }
+    }
+}
+
+pub struct Animation {
+    config: AnimationConfig,
+    out: Box<dyn Write>,
+    commands: Receiver<Command>,
+    frames: usize,
+}
+
+impl Animation {
+    pub fn new(
+        config: AnimationConfig,
+        out: Box<dyn Write>,
+        commands: Receiver<Command>,
+    ) -> Animation {
+        Animation {
+            config,
+            out,
+            commands,
+            frames: 0,
+        }
+    }
+
+    /// Draws to stdout and reads one command per line from stdin.
+    pub fn terminal(config: AnimationConfig) -> Animation {
+        let (sender, commands) = channel();
+        thread::spawn(move || {
+            for line in stdin().lock().lines().map_while(Result::ok) {
+                if let Some(command) = Command::parse(&line) {
+                    if sender.send(command).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+        Animation::new(config, Box::new(stdout()), commands)
+    }
+
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    pub fn delay(&self) -> Duration {
+        self.config.delay
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.config.paused
+    }
+
+    fn wait(&mut self) -> bool {
+        loop {
+            let received = if self.config.paused {
+                self.commands
+                    .recv()
+                    .map_err(|_| RecvTimeoutError::Disconnected)
+            } else {
+                self.commands.recv_timeout(self.config.delay)
+            };
+            match received {
+                Ok(Command::Step) => {
+                    self.config.paused = true;
+                    return true;
+                }
+                Ok(Command::Toggle) => {
+                    self.config.paused = !self.config.paused;
+                    if !self.config.paused {
+                        return true;
+                    }
+                }
+                Ok(Command::Faster) => {
+                    self.config.delay = (self.config.delay / 2).max(Duration::from_millis(1))
+                }
+                Ok(Command::Slower) => {
+                    self.config.delay = (self.config.delay * 2).min(Duration::from_secs(10))
+                }
+                Ok(Command::Quit) => return false,
+                Err(RecvTimeoutError::Timeout) => return true,
+                // Without a viewer to send commands the animation just plays.
+                Err(RecvTimeoutError::Disconnected) => {
+                    thread::sleep(self.config.delay);
+                    return true;
+                }
+            }
+        }
+    }
+}
+
+impl Frames for Animation {
+    /// Shows the frame, or the configured window of it around `focus`, and waits until the
+    /// next one is due. Returns false once the viewer quit.
+    fn frame(
+        &mut self,
+        bounds: &Viewport,
+        focus: (i32, i32),
+        caption: &str,
+        cell: &mut dyn FnMut(i32, i32) -> Cell,
+    ) -> bool {
+        let view = match self.config.window {
+            Some(size) => bounds.window(focus, size),
+            None => *bounds,
+        };
+        self.frames += 1;
+        let frame = render(&view, &self.config.theme, cell);
+        let _ = write!(
+            self.out,
+            "\x1b[2J\x1b[H{}{}\nframe {}, {:?} per frame, {}\n",
+            frame, caption, self.frames, self.config.delay, CONTROLS
+        );
+        let _ = self.out.flush();
+        self.wait()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::sink;
+    use std::sync::mpsc::Sender;
+
+    fn animation(paused: bool) -> (Animation, Sender<Command>) {
+        let (sender, commands) = channel();
+        let config = AnimationConfig {
+            theme: Theme::named("plain").unwrap(),
+            delay: Duration::from_millis(1),
+            window: Some((3, 3)),
+            paused,
+        };
+        (Animation::new(config, Box::new(sink()), commands), sender)
+    }
+
+    #[test]
+    pub fn test_viewport() {
+        let view = Viewport::bounding([(2, 5), (-1, 3), (4, 0)]).unwrap();
+        assert_eq!(view, Viewport::new(-1, 0, 4, 5));
+        assert_eq!(view.center(), (1, 2));
+        assert_eq!(Viewport::bounding([]), None);
+
+        assert_eq!(view.window((1, 2), (3, 3)), Viewport::new(0, 1, 2, 3));
+        assert_eq!(view.window((-1, 5), (3, 3)), Viewport::new(-1, 3, 1, 5));
+        assert_eq!(view.window((0, 0), (10, 2)), Viewport::new(-1, 0, 4, 1));
+    }
+
+    #[test]
+    pub fn test_render() {
+        let theme = Theme::named("plain").unwrap();
+        let frame = render(&Viewport::new(0, 0, 1, 2), &theme, |y, x| match (y, x) {
+            (0, 0) => Cell::Actor,
+            (0, _) => Cell::Wall,
+            (1, 0) => Cell::Blank,
+            (1, 1) => Cell::Marker('>'),
+            _ => Cell::Empty,
+        });
+        assert_eq!(frame, "@##\n >.\n");
+        assert!(Theme::NAMES.iter().all(|n| Theme::named(n).is_some()));
+    }
+
+    #[test]
+    pub fn test_command_parse() {
+        assert_eq!(Command::parse(""), Some(Command::Step));
+        assert_eq!(Command::parse(" p\n"), Some(Command::Toggle));
+        assert_eq!(Command::parse("q"), Some(Command::Quit));
+        assert_eq!(Command::parse("x"), None);
+    }
+
+    #[test]
+    pub fn test_controls() {
+        let view = Viewport::new(0, 0, 9, 9);
+        let (mut a, sender) = animation(true);
+        for c in [
+            Command::Slower,
+            Command::Slower,
+            Command::Faster,
+            Command::Step,
+        ] {
+            sender.send(c).unwrap();
+        }
+        assert!(a.frame(&view, (5, 5), "", &mut |_, _| Cell::Empty));
+        assert_eq!(a.delay(), Duration::from_millis(2));
+        assert!(a.is_paused());
+
+        sender.send(Command::Toggle).unwrap();
+        assert!(a.frame(&view, (5, 5), "", &mut |_, _| Cell::Empty));
+        assert!(!a.is_paused());
+        assert!(a.frame(&view, (5, 5), "", &mut |_, _| Cell::Empty));
+
+        sender.send(Command::Quit).unwrap();
+        assert!(!a.frame(&view, (5, 5), "", &mut |_, _| Cell::Empty));
+        assert_eq!(a.frames(), 4);
+
+        drop(sender);
+        assert!(a.frame(&view, (5, 5), "", &mut |_, _| Cell::Empty));
+    }
+}
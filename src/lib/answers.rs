@@ -0,0 +1,212 @@
+use crate::parse::{ParseError, Source};
+use crate::runner::DayResult;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+pub const ANSWERS: &str = include_str!("answers.txt");
+
+type Key = (u8, u8, String);
+
+/// Known answers keyed by day, part and input name.
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    answers: HashMap<Key, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String, found: String },
+    Missing,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartCheck {
+    pub day: u8,
+    pub part: u8,
+    pub check: Check,
+}
+
+impl Answers {
+    /// Reads lines of `day part input answer`, skipping blank lines and `#` comments.
+    pub fn parse(file: &str) -> Result<Answers, ParseError> {
+        let source = Source::new(file);
+        let mut answers = HashMap::new();
+        for l in source.lines() {
+            if l.trim().is_empty() || l.starts_with('#') {
+                continue;
+            }
+            let (day, rest) = source.split_once(l, " ")?;
+            let (part, rest) = source.split_once(rest, " ")?;
+            let (input_name, answer) = source.split_once(rest, " ")?;
+            let part = match source.number(part)? {
+                p @ (1 | 2) => p,
+                _ => return Err(source.error(part, "part 1 or 2")),
+            };
+            answers.insert(
+                (source.number(day)?, part, input_name.to_string()),
+                unescape(answer.trim_end()),
+            );
+        }
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8, input_name: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, part, input_name.to_string()))
+            .map(|a| a.as_str())
+    }
+
+    pub fn check(&self, result: &DayResult, input_name: &str) -> Vec<PartCheck> {
+        [(1, Some(&result.part_1)), (2, result.part_2.as_ref())]
+            .into_iter()
+            .filter_map(|(part, p)| p.map(|p| (part, p)))
+            .map(|(part, p)| PartCheck {
+                day: result.day,
+                part,
+                check: match self.get(result.day, part, input_name) {
+                    Some(expected) if expected == p.answer => Check::Pass,
+                    Some(expected) => Check::Fail {
+                        expected: expected.to_string(),
+                        found: p.answer.clone(),
+                    },
+                    None => Check::Missing,
+                },
+            })
+            .collect()
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Check::Pass => write!(f, "pass"),
+            Check::Fail { expected, found } => write!(
+                f,
+                "FAIL, expected {} but got {}",
+                escape(expected),
+                escape(found)
+            ),
+            Check::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+pub fn format_checks(checks: &[PartCheck]) -> String {
+    let mut report = String::new();
+    for c in checks {
+        report.push_str(&format!("Day {:02} part {}: {}\n", c.day, c.part, c.check));
+    }
+    let count = |f: fn(&Check) -> bool| checks.iter().filter(|c| f(&c.check)).count();
+    report.push_str(&format!(
+        "{} passed, {} failed, {} missing\n",
+        count(|c| *c == Check::Pass),
+        count(|c| matches!(c, Check::Fail { .. })),
+        count(|c| *c == Check::Missing),
+    ));
+    report
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+fn unescape(answer: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => {
+                unescaped.push('\n');
+                chars.next();
+            }
+            ('\\', Some('\\')) => {
+                unescaped.push('\\');
+                chars.next();
+            }
+            (c, _) => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::PartResult;
+    use std::time::Duration;
+
+    fn result(day: u8, part_1: &str, part_2: Option<&str>) -> DayResult {
+        let part = |answer: &str| PartResult {
+            answer: answer.into(),
+            time: Duration::ZERO,
+        };
+        DayResult {
+            day,
+            parse_time: Duration::ZERO,
+            part_1: part(part_1),
+            part_2: part_2.map(part),
+        }
+    }
+
+    #[test]
+    pub fn test_parse() {
+        let answers =
+            Answers::parse("# comment\n\n01 1 main 68442\n10 2 test \\n#.\\n.#\\n\n").unwrap();
+        assert_eq!(answers.get(1, 1, "main"), Some("68442"));
+        assert_eq!(answers.get(10, 2, "test"), Some("\n#.\n.#\n"));
+        assert_eq!(answers.get(1, 2, "main"), None);
+        assert_eq!(answers.get(1, 1, "test"), None);
+
+        let error = Answers::parse("01 3 main 5").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+        assert!(Answers::parse("01 1 main").is_err());
+    }
+
+    #[test]
+    pub fn test_bundled_answers() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.get(1, 2, "main"), Some("204837"));
+        assert!(answers.get(10, 2, "main").unwrap().starts_with("\n###..#"));
+    }
+
+    #[test]
+    pub fn test_check() {
+        let answers = Answers::parse("01 1 main 3\n01 2 main 4\n02 1 main 5").unwrap();
+        assert_eq!(
+            answers.check(&result(1, "3", Some("5")), "main"),
+            [
+                PartCheck {
+                    day: 1,
+                    part: 1,
+                    check: Check::Pass,
+                },
+                PartCheck {
+                    day: 1,
+                    part: 2,
+                    check: Check::Fail {
+                        expected: "4".into(),
+                        found: "5".into(),
+                    },
+                },
+            ]
+        );
+        let checks = answers.check(&result(2, "5", Some("6")), "main");
+        assert_eq!(checks[1].check, Check::Missing);
+        assert_eq!(answers.check(&result(2, "5", None), "main").len(), 1);
+    }
+
+    #[test]
+    pub fn test_format_checks() {
+        let answers = Answers::parse("01 1 main 3\n01 2 main 4\\n2").unwrap();
+        let mut checks = answers.check(&result(1, "3", Some("4")), "main");
+        checks.extend(answers.check(&result(2, "1", None), "main"));
+        assert_eq!(
+            format_checks(&checks),
+            "Day 01 part 1: pass\n\
+             Day 01 part 2: FAIL, expected 4\\n2 but got 4\n\
+             Day 02 part 1: missing\n\
+             1 passed, 1 failed, 1 missing\n"
+        );
+    }
+}
@@ -0,0 +1,343 @@
+use crate::day::Day;
+use crate::parse::{ParseError, Source};
+use crate::runner::{align_columns, format_duration, timed};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Median slowdown against the baseline above which a phase is flagged.
+pub const REGRESSION_THRESHOLD: f64 = 0.1;
+
+#[derive(Debug, Clone)]
+pub struct BenchConfig {
+    pub warmup: Duration,
+    pub samples: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warmup: Duration::from_millis(200),
+            samples: 20,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Phase {
+    fn key(&self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Part1 => "part_1",
+            Phase::Part2 => "part_2",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Phase> {
+        [Phase::Parse, Phase::Part1, Phase::Part2]
+            .into_iter()
+            .find(|p| p.key() == key)
+    }
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "Parse"),
+            Phase::Part1 => write!(f, "Part 1"),
+            Phase::Part2 => write!(f, "Part 2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub p10: Duration,
+    pub p90: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        samples.sort();
+        let percentile = |p: f64| samples[((samples.len() - 1) as f64 * p).round() as usize];
+        Stats {
+            median: percentile(0.5),
+            p10: percentile(0.1),
+            p90: percentile(0.9),
+            min: samples[0],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DayBench {
+    pub day: u8,
+    pub phases: Vec<(Phase, Stats)>,
+}
+
+pub fn bench<D: Day>(
+    file: &str,
+    input_name: &str,
+    config: &BenchConfig,
+) -> Result<DayBench, ParseError> {
+    let params = D::params(input_name);
+    let input = D::parse(file)?;
+
+    let mut phases = vec![
+        (Phase::Parse, sample(config, || D::parse(black_box(file)))),
+        (
+            Phase::Part1,
+            sample(config, || D::part_1(black_box(&input), &params)),
+        ),
+    ];
+    if D::has_part_2() {
+        phases.push((
+            Phase::Part2,
+            sample(config, || D::part_2(black_box(&input), &params)),
+        ));
+    }
+    Ok(DayBench {
+        day: D::DAY,
+        phases,
+    })
+}
+
+fn sample<T, F: FnMut() -> T>(config: &BenchConfig, mut f: F) -> Stats {
+    let start = Instant::now();
+    loop {
+        black_box(f());
+        if start.elapsed() >= config.warmup {
+            break;
+        }
+    }
+
+    Stats::from_samples(
+        (0..config.samples.max(1))
+            .map(|_| timed(|| black_box(f())).1)
+            .collect(),
+    )
+}
+
+/// Saved results of an earlier run, keyed by day and phase.
+pub type Baseline = HashMap<(u8, Phase), Stats>;
+
+/// Writes one `day phase median p10 p90 min max` line per phase, with times in nanoseconds.
+pub fn save(benches: &[DayBench]) -> String {
+    let mut file = String::from("# day phase median p10 p90 min max (ns)\n");
+    for b in benches {
+        for (phase, s) in &b.phases {
+            file.push_str(&format!(
+                "{:02} {} {} {} {} {} {}\n",
+                b.day,
+                phase.key(),
+                s.median.as_nanos(),
+                s.p10.as_nanos(),
+                s.p90.as_nanos(),
+                s.min.as_nanos(),
+                s.max.as_nanos()
+            ));
+        }
+    }
+    file
+}
+
+pub fn load(file: &str) -> Result<Baseline, ParseError> {
+    let source = Source::new(file);
+    let mut baseline = Baseline::new();
+    for l in source.lines() {
+        if l.trim().is_empty() || l.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = l.split_ascii_whitespace().collect();
+        let [day, phase, times @ ..] = &fields[..] else {
+            return Err(source.error(l, "a day and a phase"));
+        };
+        if times.len() != 5 {
+            return Err(source.error(l, "five times"));
+        }
+        let phase =
+            Phase::from_key(phase).ok_or_else(|| source.error(phase, "parse, part_1 or part_2"))?;
+        let times = times
+            .iter()
+            .map(|t| source.number(t).map(Duration::from_nanos))
+            .collect::<Result<Vec<Duration>, ParseError>>()?;
+        baseline.insert(
+            (source.number(day)?, phase),
+            Stats {
+                median: times[0],
+                p10: times[1],
+                p90: times[2],
+                min: times[3],
+                max: times[4],
+            },
+        );
+    }
+    Ok(baseline)
+}
+
+/// Relative change of the median, positive when slower than the baseline.
+pub fn change(stats: &Stats, baseline: &Stats) -> f64 {
+    stats.median.as_secs_f64() / baseline.median.as_secs_f64().max(f64::MIN_POSITIVE) - 1.0
+}
+
+pub fn format_bench_table(benches: &[DayBench], baseline: Option<&Baseline>) -> String {
+    let mut header: Vec<String> = ["Day", "Phase", "Median", "P10", "P90", "Min", "Max"]
+        .map(String::from)
+        .to_vec();
+    if baseline.is_some() {
+        header.extend(["Baseline".to_string(), "Change".to_string()]);
+    }
+
+    let mut rows = vec![header];
+    for b in benches {
+        for (phase, s) in &b.phases {
+            let mut row = vec![
+                format!("{:02}", b.day),
+                phase.to_string(),
+                format_duration(s.median),
+                format_duration(s.p10),
+                format_duration(s.p90),
+                format_duration(s.min),
+                format_duration(s.max),
+            ];
+            match baseline.map(|bl| bl.get(&(b.day, *phase))) {
+                Some(Some(old)) => {
+                    let c = change(s, old);
+                    row.push(format_duration(old.median));
+                    row.push(format!(
+                        "{:+.1}%{}",
+                        c * 100.0,
+                        if c > REGRESSION_THRESHOLD {
+                            "  regression"
+                        } else {
+                            ""
+                        }
+                    ));
+                }
+                Some(None) => row.extend(["-".to_string(), "new".to_string()]),
+                None => {}
+            }
+            rows.push(row);
+        }
+    }
+    align_columns(&rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(m: &[u64]) -> Vec<Duration> {
+        m.iter().map(|m| Duration::from_micros(*m)).collect()
+    }
+
+    fn day_bench(day: u8, median: u64) -> DayBench {
+        DayBench {
+            day,
+            phases: vec![
+                (Phase::Parse, Stats::from_samples(micros(&[10]))),
+                (Phase::Part1, Stats::from_samples(micros(&[median]))),
+            ],
+        }
+    }
+
+    #[test]
+    pub fn test_stats() {
+        let stats = Stats::from_samples(micros(&[9, 1, 8, 2, 7, 3, 6, 4, 5, 10, 11]));
+        assert_eq!(stats.median, Duration::from_micros(6));
+        assert_eq!(stats.p10, Duration::from_micros(2));
+        assert_eq!(stats.p90, Duration::from_micros(10));
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.max, Duration::from_micros(11));
+
+        let stats = Stats::from_samples(micros(&[4]));
+        assert_eq!(
+            (stats.min, stats.median, stats.max),
+            (stats.p10, stats.p90, stats.p10)
+        );
+    }
+
+    #[test]
+    pub fn test_save_and_load() {
+        let benches = [day_bench(1, 20), day_bench(25, 30)];
+        let file = save(&benches);
+        assert_eq!(
+            file.lines().nth(1),
+            Some("01 parse 10000 10000 10000 10000 10000")
+        );
+        let baseline = load(&file).unwrap();
+        assert_eq!(baseline.len(), 4);
+        assert_eq!(baseline[&(25, Phase::Part1)], benches[1].phases[1].1);
+
+        let error = load("01 part_3 1 2 3 4 5").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+        assert!(load("01 parse 1 2 3").is_err());
+    }
+
+    #[test]
+    pub fn test_format_bench_table() {
+        let baseline = load(&save(&[day_bench(1, 10)])).unwrap();
+        let table = format_bench_table(&[day_bench(1, 20), day_bench(2, 10)], Some(&baseline));
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Day  Phase   Median  P10     P90     Min     Max     Baseline  Change"
+        );
+        assert_eq!(
+            lines[1],
+            "01   Parse   10.0µs  10.0µs  10.0µs  10.0µs  10.0µs  10.0µs    +0.0%"
+        );
+        assert!(lines[2].ends_with("10.0µs    +100.0%  regression"));
+        assert!(lines[4].ends_with("-         new"));
+        assert!(!format_bench_table(&[day_bench(1, 20)], None).contains("Baseline"));
+    }
+
+    #[test]
+    pub fn test_bench() {
+        struct Count;
+        impl Day for Count {
+            const DAY: u8 = 7;
+            const MAIN_INPUT: &'static str = "abc";
+            const TEST_INPUT: &'static str = "a";
+            type Input = usize;
+            type Params = ();
+            type Answer1 = usize;
+            type Answer2 = crate::day::NoPart2;
+            fn parse(file: &str) -> Result<usize, ParseError> {
+                Ok(file.len())
+            }
+            fn part_1(input: &usize, _params: &()) -> usize {
+                *input
+            }
+            fn part_2(_input: &usize, _params: &()) -> crate::day::NoPart2 {
+                crate::day::NoPart2
+            }
+            fn generate(rng: &mut crate::random::Rng, size: usize) -> String {
+                "a".repeat(rng.below(size + 1))
+            }
+        }
+
+        let config = BenchConfig {
+            warmup: Duration::ZERO,
+            samples: 3,
+        };
+        let b = bench::<Count>("abc", "main", &config).unwrap();
+        assert_eq!(b.day, 7);
+        assert_eq!(
+            b.phases.iter().map(|(p, _)| *p).collect::<Vec<Phase>>(),
+            [Phase::Parse, Phase::Part1]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+/// Where two circles meet, with points in (y, x) order like the centres.
 #[derive(Debug)]
 pub enum CircleIntersection {
     Nope,
@@ -5,7 +6,7 @@ pub enum CircleIntersection {
     Intersecting(((f64, f64), (f64, f64))),
 }
 
-fn calc_circle_intersection(
+pub fn calc_circle_intersection(
     s1_y: f64,
     s1_x: f64,
     s1_r: f64,
@@ -28,14 +29,54 @@ fn calc_circle_intersection(
     }
 
     let p3 = (
-        p5_x - ((h * (s2_y - s1_y)) / (d)),
         p5_y + ((h * (s2_x - s1_x)) / (d)),
+        p5_x - ((h * (s2_y - s1_y)) / (d)),
     );
 
     let p4 = (
-        p5_x + ((h * (s2_y - s1_y)) / (d)),
         p5_y - ((h * (s2_x - s1_x)) / (d)),
+        p5_x + ((h * (s2_y - s1_y)) / (d)),
     );
 
     CircleIntersection::Intersecting((p3, p4))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_no_intersection() {
+        assert!(matches!(
+            calc_circle_intersection(0.0, 0.0, 1.0, 0.0, 5.0, 1.0),
+            CircleIntersection::Nope
+        ));
+    }
+
+    #[test]
+    pub fn test_touching() {
+        match calc_circle_intersection(0.0, 0.0, 1.0, 0.0, 2.0, 1.0) {
+            CircleIntersection::Touching((y, x)) => assert_eq!((y, x), (0.0, 1.0)),
+            c => panic!("expected touching circles, got {:?}", c),
+        }
+    }
+
+    #[test]
+    pub fn test_intersecting() {
+        let root = 3f64.sqrt();
+        match calc_circle_intersection(0.0, 0.0, 2.0, 0.0, 2.0, 2.0) {
+            CircleIntersection::Intersecting((p3, p4)) => {
+                assert_eq!((p3, p4), ((root, 1.0), (-root, 1.0)))
+            }
+            c => panic!("expected intersecting circles, got {:?}", c),
+        }
+        match calc_circle_intersection(1.0, 0.0, 1.0, 1.0, 1.0, 1.0) {
+            CircleIntersection::Intersecting((p3, p4)) => {
+                let (y, x) = (3f64.sqrt() / 2.0, 0.5);
+                assert!((p3.0 - (1.0 + y)).abs() < 1e-9 && (p3.1 - x).abs() < 1e-9);
+                assert!((p4.0 - (1.0 - y)).abs() < 1e-9 && (p4.1 - x).abs() < 1e-9);
+            }
+            c => panic!("expected intersecting circles, got {:?}", c),
+        }
+    }
+}
@@ -0,0 +1,387 @@
+use crate::parse::{ParseError, Source};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// Where a parser gave up: the rest of the input at that point and what it wanted to see there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    pub at: &'a str,
+    pub expected: String,
+}
+
+impl<'a> Failure<'a> {
+    pub fn new(at: &'a str, expected: impl Into<String>) -> Failure<'a> {
+        Failure {
+            at,
+            expected: expected.into(),
+        }
+    }
+
+    /// Keeps the failure that got further into the input, or both expectations on a tie.
+    fn furthest(self, other: Failure<'a>) -> Failure<'a> {
+        match self.at.len().cmp(&other.at.len()) {
+            Ordering::Less => self,
+            Ordering::Greater => other,
+            Ordering::Equal => {
+                Failure::new(self.at, format!("{} or {}", self.expected, other.expected))
+            }
+        }
+    }
+}
+
+pub type Parsed<'a, T> = Result<(T, &'a str), Failure<'a>>;
+
+/// Skips the spaces and tabs every parser allows in front of what it matches.
+fn spaces(input: &str) -> &str {
+    input.trim_start_matches([' ', '\t'])
+}
+
+/// Whether `failure` happened right at the start of `input`, before anything was consumed.
+fn at_start(failure: &Failure, input: &str) -> bool {
+    failure.at.len() == spaces(input).len()
+}
+
+/// Something that reads a `T` from the front of the input and returns it with the rest.
+///
+/// Every parser skips spaces and tabs in front of what it matches, so inputs can be described
+/// word by word. Line breaks have to be matched explicitly.
+pub trait Parser<'a, T> {
+    fn parse(&self, input: &'a str) -> Parsed<'a, T>;
+
+    fn map<U, F>(self, f: F) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+        F: Fn(T) -> U,
+    {
+        move |input: &'a str| self.parse(input).map(|(t, rest)| (f(t), rest))
+    }
+
+    /// Replaces the result with `value`, mostly for keywords that stand for an enum variant.
+    fn to<U: Clone>(self, value: U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        self.map(move |_| value.clone())
+    }
+
+    /// Like `map`, but `f` may reject the result with what it expected instead. The error points
+    /// at the start of what this parser matched.
+    fn try_map<U, F>(self, f: F) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+        F: Fn(T) -> Result<U, String>,
+    {
+        move |input: &'a str| {
+            let (t, rest) = self.parse(input)?;
+            f(t).map(|u| (u, rest))
+                .map_err(|expected| Failure::new(spaces(input), expected))
+        }
+    }
+
+    /// Returns the result together with the slice of the input it was read from, so later checks
+    /// can still point at it.
+    fn spanned(self) -> impl Parser<'a, (T, &'a str)>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            let start = spaces(input);
+            let (t, rest) = self.parse(start)?;
+            Ok(((t, &start[..start.len() - rest.len()]), rest))
+        }
+    }
+
+    fn then<U, P: Parser<'a, U>>(self, next: P) -> impl Parser<'a, (T, U)>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            let (t, rest) = self.parse(input)?;
+            let (u, rest) = next.parse(rest)?;
+            Ok(((t, u), rest))
+        }
+    }
+
+    fn ignore_then<U, P: Parser<'a, U>>(self, next: P) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        self.then(next).map(|(_, u)| u)
+    }
+
+    fn then_ignore<U, P: Parser<'a, U>>(self, next: P) -> impl Parser<'a, T>
+    where
+        Self: Sized,
+    {
+        self.then(next).map(|(t, _)| t)
+    }
+
+    /// Tries `other` from the same spot if this parser fails. If both fail, the error is the one
+    /// that got further.
+    fn or<P: Parser<'a, T>>(self, other: P) -> impl Parser<'a, T>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| match self.parse(input) {
+            Ok(parsed) => Ok(parsed),
+            Err(failure) => other.parse(input).map_err(|f| failure.furthest(f)),
+        }
+    }
+
+    /// `None` if this parser fails before matching anything. A failure halfway through is still
+    /// an error.
+    fn opt(self) -> impl Parser<'a, Option<T>>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| match self.parse(input) {
+            Ok((t, rest)) => Ok((Some(t), rest)),
+            Err(failure) if at_start(&failure, input) => Ok((None, input)),
+            Err(failure) => Err(failure),
+        }
+    }
+
+    /// Zero or more repetitions, stopping at the first one that fails before matching anything.
+    fn many(self) -> impl Parser<'a, Vec<T>>
+    where
+        Self: Sized,
+    {
+        move |mut input: &'a str| {
+            let mut items = Vec::new();
+            loop {
+                match self.parse(input) {
+                    Ok((t, rest)) => {
+                        items.push(t);
+                        input = rest;
+                    }
+                    Err(failure) if at_start(&failure, input) => return Ok((items, input)),
+                    Err(failure) => return Err(failure),
+                }
+            }
+        }
+    }
+
+    /// One or more repetitions with `separator` between them. After a separator another item has
+    /// to follow.
+    fn separated_by<U, P: Parser<'a, U>>(self, separator: P) -> impl Parser<'a, Vec<T>>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            let (t, mut input) = self.parse(input)?;
+            let mut items = vec![t];
+            loop {
+                match separator.parse(input) {
+                    Ok((_, rest)) => {
+                        let (t, rest) = self.parse(rest)?;
+                        items.push(t);
+                        input = rest;
+                    }
+                    Err(failure) if at_start(&failure, input) => return Ok((items, input)),
+                    Err(failure) => return Err(failure),
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(&'a str) -> Parsed<'a, T>,
+{
+    fn parse(&self, input: &'a str) -> Parsed<'a, T> {
+        self(input)
+    }
+}
+
+/// Exactly `text`.
+pub fn literal<'a>(text: &'static str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let input = spaces(input);
+        match input.strip_prefix(text) {
+            Some(rest) => Ok((&input[..text.len()], rest)),
+            None => Err(Failure::new(input, format!("'{}'", text))),
+        }
+    }
+}
+
+/// Digits with an optional sign, read as any number type.
+pub fn number<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: &'a str| {
+        let input = spaces(input);
+        let sign = usize::from(input.starts_with(['-', '+']));
+        let digits = input[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(input.len() - sign);
+        let (text, rest) = input.split_at(sign + digits);
+        match text.parse() {
+            Ok(n) if digits > 0 => Ok((n, rest)),
+            _ => Err(Failure::new(input, "a number")),
+        }
+    }
+}
+
+/// A run of letters, digits and underscores, like names and labels.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let input = spaces(input);
+        let len = input
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(input.len());
+        match len {
+            0 => Err(Failure::new(input, "a word")),
+            _ => Ok(input.split_at(len)),
+        }
+    }
+}
+
+/// The rest of `input` after a `\n` or `\r\n` at its start.
+fn line_ending(input: &str) -> Option<&str> {
+    input
+        .strip_prefix('\n')
+        .or_else(|| input.strip_prefix("\r\n"))
+}
+
+/// Skips line endings up to the next line with content, which has to exist. A single ending is a
+/// line break, more than one means there were blank lines in between.
+fn line_breaks<'a>(input: &'a str, blank_lines: bool) -> Parsed<'a, ()> {
+    let expected = if blank_lines {
+        "a blank line"
+    } else {
+        "a line break"
+    };
+    let start = spaces(input);
+    let mut rest = start;
+    let mut breaks = 0;
+    while let Some(next) = line_ending(rest) {
+        let next = spaces(next);
+        if next.is_empty() {
+            break;
+        }
+        rest = next;
+        breaks += 1;
+    }
+    match (breaks, blank_lines) {
+        (1, false) | (2.., true) => Ok(((), rest)),
+        _ => Err(Failure::new(start, expected)),
+    }
+}
+
+/// The end of a line that is followed by another line with content.
+pub fn line_break<'a>() -> impl Parser<'a, ()> {
+    move |input: &'a str| line_breaks(input, false)
+}
+
+/// The end of a line and one or more blank lines, followed by a line with content.
+pub fn blank_lines<'a>() -> impl Parser<'a, ()> {
+    move |input: &'a str| line_breaks(input, true)
+}
+
+/// One `line` per line.
+pub fn lines<'a, T>(line: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    line.separated_by(line_break())
+}
+
+/// One `section` per block of lines, with blank lines between the blocks.
+pub fn sections<'a, T>(section: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    section.separated_by(blank_lines())
+}
+
+/// Nothing but whitespace up to the end of the input.
+pub fn end<'a>() -> impl Parser<'a, ()> {
+    move |input: &'a str| {
+        let input = input.trim_start();
+        match input.is_empty() {
+            true => Ok(((), input)),
+            false => Err(Failure::new(input, "end of input")),
+        }
+    }
+}
+
+/// Runs `parser` over the whole of `source`, reporting failures with their line and column and
+/// the word that was found there.
+pub fn parse_all<'a, T>(source: &Source<'a>, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+    match parser.then_ignore(end()).parse(source.file()) {
+        Ok((t, _)) => Ok(t),
+        Err(failure) => {
+            let found = failure
+                .at
+                .find(char::is_whitespace)
+                .map_or(failure.at, |i| &failure.at[..i]);
+            Err(source.error(found, failure.expected))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run<'a, T>(file: &'a str, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+        parse_all(&Source::new(file), parser)
+    }
+
+    #[test]
+    pub fn test_tokens() {
+        assert_eq!(
+            run("  move 12", literal("move").ignore_then(number())),
+            Ok(12)
+        );
+        assert_eq!(run("x=-3", literal("x=").ignore_then(number())), Ok(-3));
+        assert_eq!(run("AA_1", word()), Ok("AA_1"));
+        assert!(run::<u8>("300", number()).is_err());
+        assert!(run::<i32>("-", number()).is_err());
+
+        let error = run("move x from", literal("move").ignore_then(number::<u8>())).unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (6, "a number"));
+        assert_eq!(error.found, "'x'");
+        let error = run("move", literal("move").then(word())).unwrap_err();
+        assert_eq!((error.column, error.found.as_str()), (5, "end of line"));
+    }
+
+    #[test]
+    pub fn test_combinators() {
+        let op = || literal("+").to('+').or(literal("*").to('*'));
+        assert_eq!(
+            run("1 * 2", number::<u8>().then(op()).then(number())),
+            Ok(((1, '*'), 2))
+        );
+        let error = run("1 / 2", number::<u8>().then(op())).unwrap_err();
+        assert_eq!(error.expected, "'+' or '*'");
+
+        let list = || number::<u8>().separated_by(literal(","));
+        assert_eq!(run("1, 2,3", list()), Ok(vec![1, 2, 3]));
+        assert_eq!(run("1, 2,", list()).unwrap_err().column, 6);
+        assert_eq!(run("a b", word().many()), Ok(vec!["a", "b"]));
+        assert_eq!(
+            run("-", literal("+").opt().then(literal("-"))),
+            Ok((None, "-"))
+        );
+
+        let even = number::<u8>().try_map(|n| match n % 2 {
+            0 => Ok(n),
+            _ => Err("an even number".into()),
+        });
+        let error = run("2 3", number::<u8>().then(even)).unwrap_err();
+        assert_eq!(
+            (error.column, error.expected.as_str()),
+            (3, "an even number")
+        );
+        assert_eq!(run(" ab", word().spanned()), Ok(("ab", "ab")));
+    }
+
+    #[test]
+    pub fn test_lines_and_sections() {
+        let file = "1 2\n3\r\n\n\n4\n5\n";
+        let parser = sections(lines(number::<u8>().many()));
+        assert_eq!(
+            run(file, parser),
+            Ok(vec![vec![vec![1, 2], vec![3]], vec![vec![4], vec![5]]])
+        );
+        let error = run("1\n2\nx\n", lines(number::<u8>())).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 1));
+        let error = run("1\n2 x", lines(number::<u8>())).unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (2, "end of input"));
+        assert!(run("1\n\n2", lines(number::<u8>())).is_err());
+    }
+}
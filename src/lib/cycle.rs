@@ -0,0 +1,161 @@
+use crate::math::modulo;
+
+/// A ring of values with a cursor. Offsets are relative to the cursor and wrap around in both
+/// directions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<T> {
+    data: Vec<T>,
+    i: usize,
+}
+
+impl<T> Cycle<T> {
+    pub fn new<D: Into<Vec<T>>>(data: D) -> Cycle<T> {
+        Cycle {
+            i: 0,
+            data: data.into(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn index(&self, i: isize) -> usize {
+        modulo(self.i as isize + i, self.data.len() as isize) as usize
+    }
+
+    pub fn current(&self) -> &T {
+        &self.data[self.i]
+    }
+
+    pub fn get(&self, i: isize) -> &T {
+        &self.data[self.index(i)]
+    }
+
+    pub fn get_mut(&mut self, i: isize) -> &mut T {
+        let index = self.index(i);
+        &mut self.data[index]
+    }
+
+    /// Moves the cursor `t` values forwards, or backwards for negative `t`.
+    pub fn turn(&mut self, t: isize) {
+        self.i = self.index(t)
+    }
+
+    /// The values starting at the cursor, once around the ring.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.data[self.i..].iter().chain(&self.data[..self.i])
+    }
+
+    /// The values starting at offset `i`, once around the ring.
+    pub fn iter_from(&self, i: isize) -> impl DoubleEndedIterator<Item = &T> {
+        let start = if self.data.is_empty() {
+            0
+        } else {
+            self.index(i)
+        };
+        self.data[start..].iter().chain(&self.data[..start])
+    }
+
+    /// The offset of the first value from the cursor onwards that matches `predicate`.
+    pub fn position<P: FnMut(&T) -> bool>(&self, predicate: P) -> Option<isize> {
+        self.iter().position(predicate).map(|i| i as isize)
+    }
+
+    /// Inserts `value` so that it ends up at offset `i`, moving the values from there on one
+    /// further. The cursor stays on its value unless `i` is a multiple of the new length, in
+    /// which case `value` becomes the current one.
+    pub fn insert(&mut self, i: isize, value: T) {
+        let len = self.data.len();
+        let index = self.i + modulo(i, len as isize + 1) as usize;
+        if index <= len {
+            self.data.insert(index, value);
+        } else {
+            self.data.insert(index - len, value);
+            self.i += 1;
+        }
+    }
+
+    /// Removes the value at offset `i`. Removing the current value moves the cursor to the next.
+    pub fn remove(&mut self, i: isize) -> T {
+        let index = self.index(i);
+        let value = self.data.remove(index);
+        if index < self.i {
+            self.i -= 1;
+        }
+        if self.i == self.data.len() {
+            self.i = 0;
+        }
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_turn() {
+        let mut cycle = Cycle::new(['a', 'b', 'c', 'd']);
+        assert_eq!(cycle.current(), &'a');
+        cycle.turn(1);
+        assert_eq!(cycle.current(), &'b');
+        cycle.turn(-2);
+        assert_eq!(cycle.current(), &'d');
+        cycle.turn(9);
+        assert_eq!(cycle.current(), &'a');
+    }
+
+    #[test]
+    pub fn test_get() {
+        let mut cycle = Cycle::new(['a', 'b', 'c', 'd']);
+        assert_eq!(cycle.get(0), &'a');
+        assert_eq!(cycle.get(5), &'b');
+        assert_eq!(cycle.get(-1), &'d');
+        cycle.turn(2);
+        assert_eq!(cycle.get(1), &'d');
+        *cycle.get_mut(-1) = 'x';
+        assert_eq!(cycle.get(-1), &'x');
+    }
+
+    #[test]
+    pub fn test_iter() {
+        let mut cycle = Cycle::new(vec![1, 2, 3, 4]);
+        cycle.turn(-1);
+        assert_eq!(cycle.iter().copied().collect::<Vec<_>>(), [4, 1, 2, 3]);
+        assert_eq!(cycle.iter().next_back(), Some(&3));
+        assert_eq!(
+            cycle.iter_from(2).copied().collect::<Vec<_>>(),
+            [2, 3, 4, 1]
+        );
+        assert_eq!(cycle.position(|n| *n == 3), Some(3));
+        assert_eq!(cycle.position(|n| *n == 5), None);
+        assert_eq!(Cycle::<u8>::new([]).iter_from(3).count(), 0);
+    }
+
+    #[test]
+    pub fn test_insert_remove() {
+        let mut cycle = Cycle::new(['a', 'b', 'c', 'd']);
+        cycle.turn(3);
+        cycle.insert(2, 'x');
+        assert_eq!(cycle.iter().collect::<String>(), "daxbc");
+        cycle.insert(0, 'y');
+        assert_eq!(cycle.iter().collect::<String>(), "ydaxbc");
+        cycle.insert(-1, 'z');
+        assert_eq!(cycle.iter().collect::<String>(), "ydaxbcz");
+
+        assert_eq!(cycle.remove(0), 'y');
+        assert_eq!(cycle.iter().collect::<String>(), "daxbcz");
+        assert_eq!(cycle.remove(-1), 'z');
+        assert_eq!(cycle.remove(1), 'a');
+        assert_eq!(cycle.iter().collect::<String>(), "dxbc");
+        cycle.turn(-1);
+        assert_eq!(cycle.remove(0), 'c');
+        assert_eq!(cycle.current(), &'d');
+        assert_eq!(cycle.len(), 3);
+    }
+}
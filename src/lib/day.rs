@@ -0,0 +1,150 @@
+use crate::animation::Frames;
+use crate::parse::ParseError;
+use crate::random::Rng;
+use std::any::TypeId;
+use std::fmt::{Display, Formatter};
+
+pub const MAIN: &str = "main";
+pub const TEST: &str = "test";
+pub const GENERATED: &str = "generated";
+
+pub trait Day {
+    const DAY: u8;
+    const MAIN_INPUT: &'static str;
+    const TEST_INPUT: &'static str;
+    /// Whether `animate` shows the simulation behind the answers.
+    const ANIMATED: bool = false;
+
+    type Input;
+    type Params: Default;
+    type Answer1: Display + 'static;
+    type Answer2: Display + 'static;
+
+    fn parse(file: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(input: &Self::Input, params: &Self::Params) -> Self::Answer1;
+    fn part_2(input: &Self::Input, params: &Self::Params) -> Self::Answer2;
+
+    /// Parameters the puzzle uses for the named input, e.g. the smaller search area of an example.
+    fn params(_input_name: &str) -> Self::Params {
+        Self::Params::default()
+    }
+
+    fn animate(_input: &Self::Input, _params: &Self::Params, _frames: &mut dyn Frames) {}
+
+    /// A slow but obviously correct solution of part 1 to check `part_1` against, if there is one.
+    fn reference_1(_input: &Self::Input, _params: &Self::Params) -> Option<Self::Answer1> {
+        None
+    }
+
+    fn reference_2(_input: &Self::Input, _params: &Self::Params) -> Option<Self::Answer2> {
+        None
+    }
+
+    /// A random but valid input of roughly `size` lines, items or cells, depending on the puzzle.
+    /// It has to be solvable with the parameters of the main input.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+
+    fn input(name: &str) -> Option<&'static str> {
+        match name {
+            MAIN => Some(Self::MAIN_INPUT),
+            TEST => Some(Self::TEST_INPUT),
+            _ => None,
+        }
+    }
+
+    fn has_part_2() -> bool {
+        TypeId::of::<Self::Answer2>() != TypeId::of::<NoPart2>()
+    }
+}
+
+/// Answer type of a second part without a solver, either because the puzzle has none, like day
+/// 25's, or because it is not solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoPart2;
+
+impl Display for NoPart2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "-")
+    }
+}
+
+/// Answer type of a part that some inputs have no answer to, like a valley the expedition can not
+/// cross. Displays as `none` then.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Maybe<T>(pub Option<T>);
+
+impl<T: Display> Display for Maybe<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            Some(answer) => write!(f, "{}", answer),
+            None => write!(f, "none"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::Source;
+
+    struct Sum;
+
+    impl Day for Sum {
+        const DAY: u8 = 1;
+        const MAIN_INPUT: &'static str = "1\n2\n3";
+        const TEST_INPUT: &'static str = "1";
+
+        type Input = Vec<u32>;
+        type Params = u32;
+        type Answer1 = u32;
+        type Answer2 = NoPart2;
+
+        fn parse(file: &str) -> Result<Vec<u32>, ParseError> {
+            let source = Source::new(file);
+            source.lines().map(|l| source.number(l)).collect()
+        }
+
+        fn part_1(input: &Vec<u32>, params: &u32) -> u32 {
+            input.iter().sum::<u32>() * params
+        }
+
+        fn part_2(_input: &Vec<u32>, _params: &u32) -> NoPart2 {
+            NoPart2
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> String {
+            (0..size).map(|_| format!("{}\n", rng.below(10))).collect()
+        }
+
+        fn params(input_name: &str) -> u32 {
+            if input_name == TEST {
+                10
+            } else {
+                1
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_day() {
+        let input = Sum::parse(Sum::input(MAIN).unwrap()).unwrap();
+        assert_eq!(Sum::part_1(&input, &Sum::params(MAIN)), 6);
+        let input = Sum::parse(Sum::input(TEST).unwrap()).unwrap();
+        assert_eq!(Sum::part_1(&input, &Sum::params(TEST)), 10);
+        assert_eq!(Sum::input("other"), None);
+        let file = Sum::generate(&mut Rng::new(1), 3);
+        assert_eq!(Sum::parse(&file).unwrap().len(), 3);
+    }
+
+    #[test]
+    pub fn test_has_part_2() {
+        assert!(!Sum::has_part_2());
+        assert_eq!(NoPart2.to_string(), "-");
+    }
+
+    #[test]
+    pub fn test_maybe() {
+        assert_eq!(Maybe(Some(12)).to_string(), "12");
+        assert_eq!(Maybe::<u32>(None).to_string(), "none");
+    }
+}
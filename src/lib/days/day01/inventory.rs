@@ -0,0 +1,298 @@
+use crate::parse::{ParseError, Source};
+use std::fmt::{Display, Formatter};
+
+/// Every snack of every elf, in input order. Displays as a summary report for the planners.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ElfInventory {
+    elves: Vec<Vec<i32>>,
+    totals: Vec<i32>,
+}
+
+/// A snack and where it is in the inventory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Item {
+    pub elf: usize,
+    pub index: usize,
+    pub calories: i32,
+}
+
+/// The elves whose totals fall in `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bucket {
+    pub start: i64,
+    pub end: i64,
+    pub count: usize,
+}
+
+impl ElfInventory {
+    /// Builds an inventory from the snacks of each elf, or `None` if the total of an elf does
+    /// not fit in an `i32`.
+    pub fn new(elves: Vec<Vec<i32>>) -> Option<ElfInventory> {
+        let totals = elves
+            .iter()
+            .map(|items| items.iter().try_fold(0i32, |acc, &c| acc.checked_add(c)))
+            .collect::<Option<_>>()?;
+        Some(ElfInventory { elves, totals })
+    }
+
+    /// Parses the puzzle input of day 1, the path `day01::parse` takes too.
+    pub fn parse(file: &str) -> Result<ElfInventory, ParseError> {
+        let source = Source::new(file);
+        let sections = source.sections();
+        if sections.is_empty() {
+            return Err(source.error(source.end(), "an elf"));
+        }
+        let mut elves = Vec::new();
+        let mut totals = Vec::new();
+        for section in sections {
+            let mut items = Vec::new();
+            let mut total = 0i32;
+            for line in section.lines() {
+                let item = line.trim_end();
+                let calories = source.number::<i32>(item)?;
+                total = total
+                    .checked_add(calories)
+                    .ok_or_else(|| source.error(item, "a total that fits in an i32"))?;
+                items.push(calories);
+            }
+            elves.push(items);
+            totals.push(total);
+        }
+        Ok(ElfInventory { elves, totals })
+    }
+
+    pub fn len(&self) -> usize {
+        self.elves.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.elves.is_empty()
+    }
+
+    pub fn items(&self, elf: usize) -> &[i32] {
+        &self.elves[elf]
+    }
+
+    pub fn item_count(&self) -> usize {
+        self.elves.iter().map(Vec::len).sum()
+    }
+
+    pub fn totals(&self) -> &[i32] {
+        &self.totals
+    }
+
+    /// The calories of all elves together, or `None` if they do not fit in an `i64`.
+    pub fn grand_total(&self) -> Option<i64> {
+        self.totals
+            .iter()
+            .try_fold(0i64, |acc, &t| acc.checked_add(t as i64))
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(self.grand_total()? as f64 / self.len() as f64)
+    }
+
+    /// The total below which a fraction `p` of the elves fall, taking the nearest elf.
+    pub fn percentile(&self, p: f64) -> Option<i32> {
+        if self.is_empty() || !(0.0..=1.0).contains(&p) {
+            return None;
+        }
+        let mut totals = self.totals.clone();
+        totals.sort_unstable();
+        Some(totals[((totals.len() - 1) as f64 * p).round() as usize])
+    }
+
+    pub fn median(&self) -> Option<i32> {
+        self.percentile(0.5)
+    }
+
+    /// Counts the elves per range of `width` calories, from the range of the smallest total to
+    /// the one of the largest, or `None` if `width` is not positive.
+    pub fn histogram(&self, width: i64) -> Option<Vec<Bucket>> {
+        if width <= 0 {
+            return None;
+        }
+        let (min, max) = match (self.totals.iter().min(), self.totals.iter().max()) {
+            (Some(&min), Some(&max)) => (min as i64, max as i64),
+            _ => return Some(Vec::new()),
+        };
+        let first = min.div_euclid(width);
+        let mut buckets: Vec<Bucket> = (first..=max.div_euclid(width))
+            .map(|b| Bucket {
+                start: b * width,
+                end: (b + 1) * width,
+                count: 0,
+            })
+            .collect();
+        for &total in &self.totals {
+            buckets[((total as i64).div_euclid(width) - first) as usize].count += 1;
+        }
+        Some(buckets)
+    }
+
+    /// The elf carrying the most calories, the first one if several carry as many.
+    pub fn richest(&self) -> Option<(usize, i32)> {
+        self.totals
+            .iter()
+            .copied()
+            .enumerate()
+            .rev()
+            .max_by_key(|&(_, total)| total)
+    }
+
+    /// The single snack with the most calories, the first one if there is a tie.
+    pub fn largest_item(&self) -> Option<Item> {
+        self.elves
+            .iter()
+            .enumerate()
+            .flat_map(|(elf, items)| {
+                items
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, &calories)| Item {
+                        elf,
+                        index,
+                        calories,
+                    })
+            })
+            .rev()
+            .max_by_key(|item| item.calories)
+    }
+}
+
+impl Display for ElfInventory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "elves     {}", self.len())?;
+        writeln!(f, "snacks    {}", self.item_count())?;
+        if self.is_empty() {
+            return Ok(());
+        }
+        match self.grand_total() {
+            Some(total) => writeln!(f, "calories  {}", total)?,
+            None => writeln!(f, "calories  too many to count")?,
+        }
+        if let Some(mean) = self.mean() {
+            writeln!(f, "mean      {:.1}", mean)?;
+        }
+        let percentile = |p| self.percentile(p).unwrap();
+        writeln!(
+            f,
+            "totals    min {}  p10 {}  median {}  p90 {}  max {}",
+            percentile(0.0),
+            percentile(0.1),
+            percentile(0.5),
+            percentile(0.9),
+            percentile(1.0)
+        )?;
+        let (elf, total) = self.richest().unwrap();
+        writeln!(f, "richest   elf {} with {}", elf, total)?;
+        if let Some(item) = self.largest_item() {
+            writeln!(
+                f,
+                "largest   {} (elf {}, snack {})",
+                item.calories, item.elf, item.index
+            )?;
+        }
+
+        let spread = percentile(1.0) as i64 - percentile(0.0) as i64;
+        let buckets = self.histogram(((spread + 9) / 10).max(1)).unwrap();
+        let widest = buckets.iter().map(|b| b.count).max().unwrap_or(1);
+        let label = |b: &Bucket| format!("{}..{}", b.start, b.end);
+        let label_width = buckets.iter().map(|b| label(b).len()).max().unwrap_or(0);
+        for b in &buckets {
+            let bar = b.count * 40 / widest;
+            writeln!(
+                f,
+                "{:>w$}  {:>5} {}",
+                label(b),
+                b.count,
+                "#".repeat(bar.max((b.count > 0) as usize)),
+                w = label_width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day01::{parse, TEST_INPUT};
+
+    #[test]
+    pub fn test_statistics() {
+        let inventory = ElfInventory::parse(TEST_INPUT).unwrap();
+        assert_eq!(inventory.totals(), &parse(TEST_INPUT).unwrap()[..]);
+        assert_eq!((inventory.len(), inventory.item_count()), (5, 10));
+        assert_eq!(inventory.items(3), &[7000, 8000, 9000]);
+        assert_eq!(inventory.grand_total(), Some(55000));
+        assert_eq!(inventory.mean(), Some(11000.0));
+        assert_eq!(inventory.median(), Some(10000));
+        assert_eq!(
+            (inventory.percentile(0.0), inventory.percentile(1.0)),
+            (Some(4000), Some(24000))
+        );
+        assert_eq!(inventory.percentile(1.5), None);
+        assert_eq!(inventory.richest(), Some((3, 24000)));
+        assert_eq!(
+            inventory.largest_item(),
+            Some(Item {
+                elf: 4,
+                index: 0,
+                calories: 10000
+            })
+        );
+    }
+
+    #[test]
+    pub fn test_histogram() {
+        let inventory = ElfInventory::parse(TEST_INPUT).unwrap();
+        let counts: Vec<(i64, usize)> = inventory
+            .histogram(5000)
+            .unwrap()
+            .iter()
+            .map(|b| (b.start, b.count))
+            .collect();
+        assert_eq!(
+            counts,
+            vec![(0, 1), (5000, 1), (10000, 2), (15000, 0), (20000, 1)]
+        );
+        let empty = ElfInventory::new(Vec::new()).unwrap();
+        assert_eq!(empty.histogram(10), Some(Vec::new()));
+        assert_eq!(inventory.histogram(0), None);
+        assert_eq!(inventory.histogram(-5), None);
+    }
+
+    #[test]
+    pub fn test_overflow() {
+        assert_eq!(ElfInventory::new(vec![vec![i32::MAX, 1]]), None);
+        let error = ElfInventory::parse(&format!("1\n\n{}\n1\n", i32::MAX)).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        let inventory = ElfInventory::new(vec![vec![i32::MAX]; 3]).unwrap();
+        assert_eq!(inventory.grand_total(), Some(3 * i32::MAX as i64));
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        for file in ["", "\n\n", "1000\n2000\n\nabc"] {
+            assert_eq!(
+                ElfInventory::parse(file).unwrap_err(),
+                parse(file).unwrap_err()
+            );
+        }
+        assert_eq!(ElfInventory::parse("").unwrap_err().expected, "an elf");
+    }
+
+    #[test]
+    pub fn test_report() {
+        let report = ElfInventory::parse(TEST_INPUT).unwrap().to_string();
+        assert!(report.contains("calories  55000\n"));
+        assert!(report.contains("richest   elf 3 with 24000\n"));
+        assert!(report.contains("largest   10000 (elf 4, snack 0)\n"));
+        assert!(report.ends_with(&format!("24000..26000      1 {}\n", "#".repeat(20))));
+        assert_eq!(report.matches('\n').count(), 7 + 11);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::day::Day;
+use crate::parse::ParseError;
+use crate::random::Rng;
+use inventory::ElfInventory;
+use stream::{top_k, Elf};
+
+pub mod inventory;
+pub mod planner;
+pub mod reference;
+pub mod stream;
+
+type Solution = i32;
+pub type ParseOutput = Vec<i32>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    Ok(ElfInventory::parse(file)?.totals().to_vec())
+}
+
+/// `size` elves carrying one to six snacks each.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let elves: Vec<String> = (0..size)
+        .map(|_| {
+            (0..rng.range(1..7))
+                .map(|_| format!("{}\n", rng.range(1000..10000)))
+                .collect()
+        })
+        .collect();
+    elves.join("\n")
+}
+
+/// The elves of the parse output, in input order.
+pub fn elves(parse_output: &ParseOutput) -> impl Iterator<Item = Elf> + '_ {
+    parse_output
+        .iter()
+        .enumerate()
+        .map(|(index, &calories)| Elf { index, calories })
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    top_k(elves(parse_output), 1)[0].calories
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    top_k(elves(parse_output), 3)
+        .iter()
+        .map(|elf| elf.calories)
+        .sum()
+}
+
+pub struct Day01;
+
+impl Day for Day01 {
+    const DAY: u8 = 1;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Solution {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 24000);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 45000);
+    }
+
+    #[test]
+    pub fn test_parse_crlf() {
+        let parse_output = parse(&TEST_INPUT.replace('\n', "\r\n")).unwrap();
+        assert_eq!(part_2(&parse_output), 45000);
+        let error = parse("1000\n2000\n\nabc").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 1));
+        assert_eq!(parse("\n\n").unwrap_err().expected, "an elf");
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day01, 0..20, 10);
+    }
+}
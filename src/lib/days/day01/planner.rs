@@ -0,0 +1,277 @@
+use super::ParseOutput;
+use std::fmt::{Display, Formatter};
+
+/// Bits `fewest_exactly` may use for its tables: 32 MiB.
+pub const MAX_TABLE_BITS: usize = 1 << 28;
+
+/// Two groups of elves, `group` carrying no more than `other`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Partition {
+    pub group: Vec<usize>,
+    pub other: Vec<usize>,
+    pub difference: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanError {
+    /// The elf, counted from 0, carries a negative total, which the planners can not work with.
+    NegativeTotal(usize),
+    /// The target is too large for the tables of `fewest_exactly` to stay within
+    /// `MAX_TABLE_BITS`.
+    TargetTooLarge(u64),
+}
+
+impl Display for PlanError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlanError::NegativeTotal(elf) => write!(f, "elf {} carries a negative total", elf),
+            PlanError::TargetTooLarge(target) => {
+                write!(
+                    f,
+                    "{} calories is too large a target to plan exactly",
+                    target
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for PlanError {}
+
+/// The totals of the elves.
+fn calories(parse_output: &ParseOutput) -> Result<Vec<u64>, PlanError> {
+    parse_output
+        .iter()
+        .enumerate()
+        .map(|(elf, &total)| u64::try_from(total).map_err(|_| PlanError::NegativeTotal(elf)))
+        .collect()
+}
+
+/// The fewest elves carrying `target` calories together, by a knapsack that keeps the fewest
+/// elves for every sum, in 32 bits, and one bit per elf and sum to find its way back. Targets
+/// that would take more than `MAX_TABLE_BITS` for that are refused.
+/// `None` if no group carries exactly `target`.
+pub fn fewest_exactly(
+    parse_output: &ParseOutput,
+    target: u64,
+) -> Result<Option<Vec<usize>>, PlanError> {
+    let calories = calories(parse_output)?;
+    if target > calories.iter().sum() {
+        return Ok(None);
+    }
+    if (calories.len() as u64 + 32).saturating_mul(target + 1) > MAX_TABLE_BITS as u64 {
+        return Err(PlanError::TargetTooLarge(target));
+    }
+    let target = target as usize;
+    let words = target / 64 + 1;
+    let mut count = vec![u32::MAX; target + 1];
+    count[0] = 0;
+    let mut taken = vec![0u64; calories.len() * words];
+    for (elf, &c) in calories.iter().enumerate() {
+        let c = c as usize;
+        for sum in (c..=target).rev() {
+            if count[sum - c] != u32::MAX && count[sum - c] + 1 < count[sum] {
+                count[sum] = count[sum - c] + 1;
+                taken[elf * words + sum / 64] |= 1 << (sum % 64);
+            }
+        }
+    }
+    if count[target] == u32::MAX {
+        return Ok(None);
+    }
+
+    let mut elves = Vec::new();
+    let mut sum = target;
+    for elf in (0..calories.len()).rev() {
+        if taken[elf * words + sum / 64] & 1 << (sum % 64) != 0 {
+            elves.push(elf);
+            sum -= calories[elf] as usize;
+        }
+    }
+    elves.reverse();
+    Ok(Some(elves))
+}
+
+/// The fewest elves carrying at least `target` calories together. Taking the richest elves
+/// first is enough, as no other group of as many elves carries more.
+/// `None` if all elves together carry less.
+pub fn fewest_at_least(
+    parse_output: &ParseOutput,
+    target: u64,
+) -> Result<Option<Vec<usize>>, PlanError> {
+    let calories = calories(parse_output)?;
+    let mut order: Vec<usize> = (0..calories.len()).collect();
+    order.sort_by_key(|&elf| std::cmp::Reverse(calories[elf]));
+    let mut sum = 0;
+    let mut elves = Vec::new();
+    for &elf in &order {
+        if sum >= target {
+            break;
+        }
+        sum += calories[elf];
+        elves.push(elf);
+    }
+    if sum < target {
+        return Ok(None);
+    }
+    elves.sort_unstable();
+    Ok(Some(elves))
+}
+
+/// Splits the elves into the two groups with the closest totals. The sums reachable so far are
+/// kept in a bitset, along with the first elf through which each of them was reached: the rest
+/// of that sum is then reachable with earlier elves only, which gives the group back.
+pub fn closest_partition(parse_output: &ParseOutput) -> Result<Partition, PlanError> {
+    let calories = calories(parse_output)?;
+    let total: u64 = calories.iter().sum();
+    let half = (total / 2) as usize;
+    let mut reachable = vec![0u64; half / 64 + 1];
+    reachable[0] = 1;
+    let mut first = vec![u32::MAX; half + 1];
+    for (elf, &c) in calories.iter().enumerate() {
+        let c = c as usize;
+        if c == 0 || c > half {
+            continue;
+        }
+        let (shift, bits) = (c / 64, c % 64);
+        for word in (shift..reachable.len()).rev() {
+            let mut shifted = reachable[word - shift] << bits;
+            if bits > 0 && word > shift {
+                shifted |= reachable[word - shift - 1] >> (64 - bits);
+            }
+            let mut new = shifted & !reachable[word];
+            reachable[word] |= new;
+            while new != 0 {
+                let sum = word * 64 + new.trailing_zeros() as usize;
+                if sum <= half {
+                    first[sum] = elf as u32;
+                }
+                new &= new - 1;
+            }
+        }
+    }
+
+    let best = (0..=half)
+        .rev()
+        .find(|&sum| reachable[sum / 64] & 1 << (sum % 64) != 0)
+        .unwrap();
+    let mut in_group = vec![false; calories.len()];
+    let mut sum = best;
+    while sum > 0 {
+        let elf = first[sum] as usize;
+        in_group[elf] = true;
+        sum -= calories[elf] as usize;
+    }
+    let (group, other) = (0..calories.len()).partition(|&elf| in_group[elf]);
+    Ok(Partition {
+        group,
+        other,
+        difference: total - 2 * best as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day01::{parse, TEST_INPUT};
+    use crate::random::Rng;
+
+    fn carried(parse_output: &ParseOutput, elves: &[usize]) -> u64 {
+        elves.iter().map(|&elf| parse_output[elf] as u64).sum()
+    }
+
+    #[test]
+    pub fn test_fewest() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(fewest_exactly(&parse_output, 21000), Ok(Some(vec![2, 4])));
+        assert_eq!(
+            fewest_exactly(&parse_output, 45000),
+            Ok(Some(vec![2, 3, 4]))
+        );
+        assert_eq!(fewest_exactly(&parse_output, 0), Ok(Some(vec![])));
+        assert_eq!(fewest_exactly(&parse_output, 5000), Ok(None));
+        assert_eq!(fewest_exactly(&parse_output, 60000), Ok(None));
+        assert_eq!(fewest_at_least(&parse_output, 30000), Ok(Some(vec![2, 3])));
+        assert_eq!(
+            fewest_at_least(&parse_output, 55000)
+                .unwrap()
+                .unwrap()
+                .len(),
+            5
+        );
+        assert_eq!(fewest_at_least(&parse_output, 0), Ok(Some(vec![])));
+        assert_eq!(fewest_at_least(&parse_output, 55001), Ok(None));
+    }
+
+    #[test]
+    pub fn test_closest_partition() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        let partition = closest_partition(&parse_output).unwrap();
+        assert_eq!(partition.difference, 1000);
+        assert_eq!(carried(&parse_output, &partition.group), 27000);
+        assert_eq!(partition.group.len() + partition.other.len(), 5);
+        assert_eq!(closest_partition(&vec![]).unwrap().difference, 0);
+    }
+
+    #[test]
+    pub fn test_plan_errors() {
+        let parse_output = parse("100\n\n-300\n\n200\n").unwrap();
+        let negative = PlanError::NegativeTotal(1);
+        assert_eq!(fewest_exactly(&parse_output, 100), Err(negative.clone()));
+        assert_eq!(fewest_at_least(&parse_output, 100), Err(negative.clone()));
+        assert_eq!(closest_partition(&parse_output), Err(negative.clone()));
+        assert_eq!(negative.to_string(), "elf 1 carries a negative total");
+
+        let parse_output = vec![i32::MAX; 100];
+        let error = fewest_exactly(&parse_output, 1 << 30).unwrap_err();
+        assert_eq!(error, PlanError::TargetTooLarge(1 << 30));
+        assert_eq!(fewest_exactly(&parse_output, 1 << 20).unwrap(), None);
+    }
+
+    #[test]
+    pub fn test_against_every_subset() {
+        let mut rng = Rng::new(1);
+        for _ in 0..50 {
+            let parse_output: ParseOutput = (0..rng.range(1..11))
+                .map(|_| rng.range(1..200) as i32)
+                .collect();
+            let n = parse_output.len();
+            let subsets: Vec<Vec<usize>> = (0..1 << n)
+                .map(|mask: usize| (0..n).filter(|i| mask & 1 << i != 0).collect())
+                .collect();
+            let total = carried(&parse_output, &(0..n).collect::<Vec<_>>());
+            let target = rng.range(0..total as i64 + 1) as u64;
+
+            let fewest = |at_least: bool| {
+                subsets
+                    .iter()
+                    .filter(|s| {
+                        let sum = carried(&parse_output, s);
+                        sum == target || at_least && sum > target
+                    })
+                    .map(Vec::len)
+                    .min()
+            };
+            let exactly = fewest_exactly(&parse_output, target).unwrap();
+            assert_eq!(exactly.as_ref().map(Vec::len), fewest(false));
+            if let Some(elves) = exactly {
+                assert_eq!(carried(&parse_output, &elves), target);
+            }
+            let at_least = fewest_at_least(&parse_output, target).unwrap().unwrap();
+            assert_eq!(Some(at_least.len()), fewest(true));
+            assert!(carried(&parse_output, &at_least) >= target);
+
+            let partition = closest_partition(&parse_output).unwrap();
+            let best = subsets
+                .iter()
+                .map(|s| (total as i64 - 2 * carried(&parse_output, s) as i64).unsigned_abs())
+                .min()
+                .unwrap();
+            assert_eq!(partition.difference, best);
+            assert_eq!(
+                total - 2 * carried(&parse_output, &partition.group),
+                partition.difference
+            );
+        }
+    }
+}
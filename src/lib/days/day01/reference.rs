@@ -0,0 +1,23 @@
+use super::{ParseOutput, Solution};
+
+pub fn part_1(elves: &ParseOutput) -> Solution {
+    let mut most = elves[0];
+    for calories in elves {
+        if *calories > most {
+            most = *calories;
+        }
+    }
+    most
+}
+
+/// Takes the elf carrying the most out of the group three times.
+pub fn part_2(elves: &ParseOutput) -> Solution {
+    let mut elves = elves.clone();
+    let mut total = 0;
+    for _ in 0..3.min(elves.len()) {
+        let most = part_1(&elves);
+        let i = elves.iter().position(|c| *c == most).unwrap();
+        total += elves.remove(i);
+    }
+    total
+}
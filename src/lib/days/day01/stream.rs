@@ -0,0 +1,222 @@
+use crate::parse::ParseError;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
+
+/// An elf, counted from 0 in input order, and the calories it carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub calories: i32,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "{}", e),
+            StreamError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(e: ParseError) -> Self {
+        StreamError::Parse(e)
+    }
+}
+
+/// The elves of an inventory read from `reader`, one line at a time, so that only the current
+/// line is ever held in memory.
+pub struct Elves<R> {
+    reader: R,
+    line: String,
+    line_number: usize,
+    index: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Elves<R> {
+    pub fn new(reader: R) -> Elves<R> {
+        Elves {
+            reader,
+            line: String::new(),
+            line_number: 0,
+            index: 0,
+            done: false,
+        }
+    }
+
+    fn error(&self, column: usize, expected: &str) -> ParseError {
+        let item = self.line.trim_end();
+        ParseError {
+            line: self.line_number,
+            column,
+            expected: expected.to_string(),
+            found: if item.is_empty() {
+                "end of line".to_string()
+            } else {
+                format!("'{}'", item)
+            },
+        }
+    }
+
+    fn next_elf(&mut self) -> Result<Option<Elf>, StreamError> {
+        let mut calories: Option<i32> = None;
+        loop {
+            self.line.clear();
+            if self.reader.read_line(&mut self.line)? == 0 {
+                break;
+            }
+            self.line_number += 1;
+            let item = self.line.trim_end();
+            if item.is_empty() {
+                if calories.is_some() {
+                    break;
+                }
+                continue;
+            }
+            let value = item.parse::<i32>().map_err(|_| self.error(1, "a number"))?;
+            calories = Some(
+                calories
+                    .unwrap_or(0)
+                    .checked_add(value)
+                    .ok_or_else(|| self.error(1, "a total that fits in an i32"))?,
+            );
+        }
+        Ok(calories.map(|calories| {
+            self.index += 1;
+            Elf {
+                index: self.index - 1,
+                calories,
+            }
+        }))
+    }
+}
+
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = self.next_elf().transpose();
+        self.done = !matches!(next, Some(Ok(_)));
+        next
+    }
+}
+
+/// The `k` elves carrying the most calories, most first, keeping no more than `k` elves in
+/// memory. Elves carrying the same amount are ranked in input order.
+pub fn top_k<I: IntoIterator<Item = Elf>>(elves: I, k: usize) -> Vec<Elf> {
+    if k == 0 {
+        return Vec::new();
+    }
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+    for elf in elves {
+        heap.push(Reverse((elf.calories, Reverse(elf.index))));
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((calories, Reverse(index)))| Elf { index, calories })
+        .collect()
+}
+
+/// Like [`top_k`], for an inventory streamed from `reader`.
+pub fn read_top_k<R: BufRead>(reader: R, k: usize) -> Result<Vec<Elf>, StreamError> {
+    let mut error = None;
+    let top = top_k(
+        Elves::new(reader).map_while(|elf| elf.map_err(|e| error = Some(e)).ok()),
+        k,
+    );
+    error.map_or(Ok(top), Err)
+}
+
+/// A table of `elves`, ranked in the order given.
+pub fn format_top(elves: &[Elf]) -> String {
+    let mut table = format!("{:>4}  {:>6}  {:>8}\n", "rank", "elf", "calories");
+    for (rank, elf) in elves.iter().enumerate() {
+        table.push_str(&format!(
+            "{:>4}  {:>6}  {:>8}\n",
+            rank + 1,
+            elf.index,
+            elf.calories
+        ));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day01::TEST_INPUT;
+    use std::io::BufReader;
+
+    fn elf(index: usize, calories: i32) -> Elf {
+        Elf { index, calories }
+    }
+
+    #[test]
+    pub fn test_read_top_k() {
+        assert_eq!(
+            read_top_k(TEST_INPUT.as_bytes(), 3).unwrap(),
+            vec![elf(3, 24000), elf(2, 11000), elf(4, 10000)]
+        );
+        assert_eq!(read_top_k(TEST_INPUT.as_bytes(), 0).unwrap(), vec![]);
+        assert_eq!(read_top_k(TEST_INPUT.as_bytes(), 10).unwrap().len(), 5);
+        let crlf = TEST_INPUT.replace('\n', "\r\n");
+        assert_eq!(
+            read_top_k(BufReader::with_capacity(4, crlf.as_bytes()), 1).unwrap(),
+            vec![elf(3, 24000)]
+        );
+    }
+
+    #[test]
+    pub fn test_format_top() {
+        let top = read_top_k(TEST_INPUT.as_bytes(), 2).unwrap();
+        assert_eq!(
+            format_top(&top),
+            "rank     elf  calories\n   1       3     24000\n   2       2     11000\n"
+        );
+    }
+
+    #[test]
+    pub fn test_top_k_ties() {
+        let elves = [elf(0, 5), elf(1, 7), elf(2, 5), elf(3, 7)];
+        assert_eq!(top_k(elves, 3), vec![elf(1, 7), elf(3, 7), elf(0, 5)]);
+    }
+
+    #[test]
+    pub fn test_read_errors() {
+        let error = read_top_k("1000\n2000\n\nabc\n".as_bytes(), 3).unwrap_err();
+        match error {
+            StreamError::Parse(e) => {
+                assert_eq!((e.line, e.column, e.found.as_str()), (4, 1, "'abc'"))
+            }
+            StreamError::Io(e) => panic!("{}", e),
+        }
+        let overflow = format!("{}\n1\n", i32::MAX);
+        assert!(matches!(
+            read_top_k(overflow.as_bytes(), 1),
+            Err(StreamError::Parse(ParseError { line: 2, .. }))
+        ));
+    }
+}
@@ -0,0 +1,390 @@
+use crate::parse::{ParseError, Source};
+use std::fmt::{Display, Formatter};
+use std::sync::OnceLock;
+
+pub const ROCK_PAPER_SCISSORS: &str = include_str!("rps.txt");
+pub const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str = include_str!("rpsls.txt");
+
+/// A round of a strategy guide, as the letters of its two columns.
+pub type Round = (char, char);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    pub fn points(self) -> u32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// How the second column of a guide is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reading {
+    Move,
+    Outcome,
+}
+
+/// A cyclic game where each of an odd number of moves beats half of the others, along with the
+/// letters the guides use for them. Moves score their position in `moves`, from 1.
+///
+/// Games are read from rule tables like `rps.txt`: a `moves` line, the `opponent` and `me`
+/// letters of every move, the `outcomes` letters for losing, drawing and winning, and one
+/// `winner verb loser` line per pair of moves, in any order. Lines starting with `#` are
+/// comments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    moves: Vec<String>,
+    beats: Vec<Vec<bool>>,
+    opponent: Vec<char>,
+    me: Vec<char>,
+    pub outcomes: [char; 3],
+}
+
+/// Letters that can not stand for the moves of a game, as it needs one per move, all different.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadLetters {
+    pub letters: Vec<char>,
+    pub moves: usize,
+}
+
+impl Display for BadLetters {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expected {} different letters, found '{}'",
+            self.moves,
+            self.letters.iter().collect::<String>()
+        )
+    }
+}
+
+impl std::error::Error for BadLetters {}
+
+fn letters<'a>(source: &Source<'a>, line: &'a str, count: usize) -> Result<Vec<char>, ParseError> {
+    let letters: Vec<&str> = line.split_whitespace().collect();
+    if letters.len() != count
+        || letters.iter().any(|l| l.chars().count() != 1)
+        || (1..count).any(|i| letters[..i].contains(&letters[i]))
+    {
+        return Err(source.error(line, format!("{} different letters", count)));
+    }
+    Ok(letters.iter().filter_map(|l| l.chars().next()).collect())
+}
+
+fn list(letters: &[char]) -> String {
+    match letters {
+        [] => String::new(),
+        [only] => only.to_string(),
+        [init @ .., last] => format!(
+            "{} or {}",
+            init.iter()
+                .map(char::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+            last
+        ),
+    }
+}
+
+impl Game {
+    pub fn parse(file: &str) -> Result<Game, ParseError> {
+        let source = Source::new(file);
+        let mut moves_line = None;
+        let mut moves: Vec<String> = Vec::new();
+        let mut opponent = None;
+        let mut me = None;
+        let mut outcomes = None;
+        let mut rules = Vec::new();
+        for line in source.lines().map(str::trim_end) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (keyword, rest) = line.split_once(' ').unwrap_or((line, ""));
+            match keyword {
+                "moves" if moves_line.is_some() => {
+                    return Err(source.error(line, "a single moves line"));
+                }
+                "moves" => {
+                    moves = rest.split_whitespace().map(String::from).collect();
+                    if moves.len() < 3 || moves.len().is_multiple_of(2) {
+                        return Err(source.error(rest, "an odd number of moves, at least 3"));
+                    }
+                    moves_line = Some(line);
+                }
+                "opponent" => opponent = Some(rest),
+                "me" => me = Some(rest),
+                "outcomes" => {
+                    let letters = letters(&source, rest, 3)?;
+                    outcomes = Some([letters[0], letters[1], letters[2]]);
+                }
+                _ => rules.push(line),
+            }
+        }
+
+        let moves_line = moves_line.ok_or_else(|| source.error(source.end(), "a moves line"))?;
+        let mut beats = vec![vec![false; moves.len()]; moves.len()];
+        for line in rules {
+            let index = |name: &str| moves.iter().position(|m| m == name);
+            let words: Vec<&str> = line.split_whitespace().collect();
+            let (winner, loser) = match words[..] {
+                [winner, _, loser] => (index(winner), index(loser)),
+                _ => (None, None),
+            };
+            match (winner, loser) {
+                (Some(w), Some(l)) if w != l && !beats[w][l] && !beats[l][w] => beats[w][l] = true,
+                (Some(w), Some(l)) if w != l => {
+                    return Err(source.error(
+                        line,
+                        format!("a single rule between {} and {}", moves[w], moves[l]),
+                    ));
+                }
+                _ => return Err(source.error(line, "a rule like 'rock crushes scissors'")),
+            }
+        }
+        for (w, row) in beats.iter().enumerate() {
+            for l in w + 1..moves.len() {
+                if !row[l] && !beats[l][w] {
+                    return Err(source.error(
+                        source.end(),
+                        format!("a rule between {} and {}", moves[w], moves[l]),
+                    ));
+                }
+            }
+            if row.iter().filter(|&&b| b).count() != moves.len() / 2 {
+                return Err(source.error(
+                    moves_line,
+                    format!("{} to beat as many moves as it loses to", moves[w]),
+                ));
+            }
+        }
+        let opponent = opponent.ok_or_else(|| source.error(source.end(), "an opponent line"))?;
+        let me = me.ok_or_else(|| source.error(source.end(), "a me line"))?;
+        Ok(Game {
+            opponent: letters(&source, opponent, moves.len())?,
+            me: letters(&source, me, moves.len())?,
+            outcomes: outcomes.ok_or_else(|| source.error(source.end(), "an outcomes line"))?,
+            moves,
+            beats,
+        })
+    }
+
+    pub fn rock_paper_scissors() -> &'static Game {
+        static GAME: OnceLock<Game> = OnceLock::new();
+        GAME.get_or_init(|| Game::parse(ROCK_PAPER_SCISSORS).unwrap())
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> &'static Game {
+        static GAME: OnceLock<Game> = OnceLock::new();
+        GAME.get_or_init(|| Game::parse(ROCK_PAPER_SCISSORS_LIZARD_SPOCK).unwrap())
+    }
+
+    /// The names of the moves, weakest scoring first.
+    pub fn moves(&self) -> &[String] {
+        &self.moves
+    }
+
+    /// The letters of the first column, one per move as in `moves`.
+    pub fn opponent(&self) -> &[char] {
+        &self.opponent
+    }
+
+    /// The letters of the second column when it is read as moves, one per move as in `moves`.
+    pub fn me(&self) -> &[char] {
+        &self.me
+    }
+
+    pub fn set_opponent(&mut self, letters: Vec<char>) -> Result<(), BadLetters> {
+        self.opponent = self.check_letters(letters)?;
+        Ok(())
+    }
+
+    pub fn set_me(&mut self, letters: Vec<char>) -> Result<(), BadLetters> {
+        self.me = self.check_letters(letters)?;
+        Ok(())
+    }
+
+    fn check_letters(&self, letters: Vec<char>) -> Result<Vec<char>, BadLetters> {
+        let different = (1..letters.len()).all(|i| !letters[..i].contains(&letters[i]));
+        if letters.len() != self.moves.len() || !different {
+            return Err(BadLetters {
+                letters,
+                moves: self.moves.len(),
+            });
+        }
+        Ok(letters)
+    }
+
+    /// Reads a guide, checking its letters against the ones of the game.
+    pub fn parse_guide(&self, file: &str) -> Result<Vec<Round>, ParseError> {
+        let source = Source::new(file);
+        let mut second = self.me.clone();
+        second.extend(self.outcomes.iter().filter(|l| !self.me.contains(l)));
+        source
+            .lines()
+            .map(|line| {
+                let (opponent, me) = source.split_once(line.trim_end(), " ")?;
+                let letter = |s: &str, letters: &[char]| {
+                    let mut chars = s.chars();
+                    match (chars.next(), chars.next()) {
+                        (Some(c), None) if letters.contains(&c) => Ok(c),
+                        _ => Err(source.error(s, list(letters))),
+                    }
+                };
+                Ok((letter(opponent, &self.opponent)?, letter(me, &second)?))
+            })
+            .collect()
+    }
+
+    pub fn outcome(&self, opponent: usize, me: usize) -> Outcome {
+        if me == opponent {
+            Outcome::Draw
+        } else if self.beats[me][opponent] {
+            Outcome::Win
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    pub fn score(&self, opponent: usize, me: usize) -> u32 {
+        me as u32 + 1 + self.outcome(opponent, me).points()
+    }
+
+    /// The move ending the round with `outcome`, the best scoring one if several do.
+    pub fn choose(&self, opponent: usize, outcome: Outcome) -> usize {
+        (0..self.moves.len())
+            .rev()
+            .find(|&me| self.outcome(opponent, me) == outcome)
+            .unwrap()
+    }
+
+    /// The move and the score of a round, or `None` if one of its letters has no meaning here.
+    pub fn play(&self, (opponent, me): Round, reading: Reading) -> Option<(usize, u32)> {
+        let opponent = self.opponent.iter().position(|&c| c == opponent)?;
+        let me = match reading {
+            Reading::Move => self.me.iter().position(|&c| c == me)?,
+            Reading::Outcome => {
+                let outcome = self.outcomes.iter().position(|&c| c == me)?;
+                self.choose(opponent, Outcome::ALL[outcome])
+            }
+        };
+        Some((me, self.score(opponent, me)))
+    }
+
+    /// The total score of a guide, or `None` if it uses letters the game does not know.
+    pub fn total(&self, guide: &[Round], reading: Reading) -> Option<u32> {
+        guide
+            .iter()
+            .map(|&round| self.play(round, reading).map(|(_, score)| score))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_rock_paper_scissors() {
+        let game = Game::rock_paper_scissors();
+        assert_eq!(game.moves(), ["rock", "paper", "scissors"]);
+        assert_eq!(game.outcome(0, 1), Outcome::Win);
+        assert_eq!(game.outcome(1, 0), Outcome::Lose);
+        assert_eq!(game.choose(2, Outcome::Win), 0);
+        assert_eq!(game.play(('A', 'Y'), Reading::Move), Some((1, 8)));
+        assert_eq!(game.play(('A', 'Y'), Reading::Outcome), Some((0, 4)));
+        assert_eq!(game.play(('D', 'Y'), Reading::Move), None);
+    }
+
+    #[test]
+    pub fn test_rock_paper_scissors_lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let guide = game.parse_guide("A Z\nE V\nD X\n").unwrap();
+        // Spock vaporizes rock, both ways, and scissors decapitate lizard.
+        assert_eq!(game.total(&guide, Reading::Move), Some(11 + 1 + 9));
+        // Rock loses to paper and Spock, and Spock scores more.
+        assert_eq!(game.play(('A', 'Z'), Reading::Outcome), Some((4, 11)));
+        let error = game.parse_guide("A Z\nF X").unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (2, "A, B, C, D or E")
+        );
+    }
+
+    #[test]
+    pub fn test_custom_letters() {
+        let mut game = Game::rock_paper_scissors().clone();
+        game.set_me(vec!['R', 'P', 'S']).unwrap();
+        game.outcomes = ['L', 'D', 'W'];
+        let guide = game.parse_guide("A P\nB L\nC W").unwrap();
+        assert_eq!(game.total(&guide, Reading::Move), None);
+        assert_eq!(game.total(&guide[..1], Reading::Move), Some(8));
+        assert_eq!(game.total(&guide[1..], Reading::Outcome), Some(1 + 7));
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        let rules = |edit: &dyn Fn(&str) -> String| Game::parse(&edit(ROCK_PAPER_SCISSORS));
+        let error = rules(&|r| r.replace("paper covers rock\n", "")).unwrap_err();
+        assert_eq!(error.expected, "a rule between rock and paper");
+        let error = rules(&|r| r.replace("paper covers rock", "rock smashes paper")).unwrap_err();
+        assert_eq!(error.expected, "rock to beat as many moves as it loses to");
+        let error = rules(&|r| r.replace("opponent A B C", "opponent A B")).unwrap_err();
+        assert_eq!((error.line, error.column), (3, 10));
+        let error = rules(&|r| r.replace("scissors\n", "scissors lizard\n")).unwrap_err();
+        assert_eq!(error.line, 2);
+        assert!(rules(&|r| r.replace("me X Y Z\n", "")).is_err());
+        assert!(rules(&|r| r.replace("cuts", "cuts through")).is_err());
+        let error = rules(&|r| format!("{}rock smashes scissors\n", r)).unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (9, "a single rule between rock and scissors")
+        );
+        let error = rules(&|r| format!("{}scissors cuts rock\n", r)).unwrap_err();
+        assert_eq!(error.expected, "a single rule between scissors and rock");
+    }
+
+    #[test]
+    pub fn test_set_letters() {
+        let mut game = Game::rock_paper_scissors().clone();
+        let error = game.set_me(vec!['R', 'P']).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "expected 3 different letters, found 'RP'"
+        );
+        assert!(game.set_opponent(vec!['R', 'P', 'R']).is_err());
+        assert_eq!(game.me(), ['X', 'Y', 'Z']);
+        game.set_opponent(vec!['R', 'P', 'S']).unwrap();
+        assert_eq!(game.play(('S', 'X'), Reading::Move), Some((0, 7)));
+    }
+
+    #[test]
+    pub fn test_letters_after_moves() {
+        let error = Game::parse(&format!(
+            "{}moves rock paper scissors lizard spock\n",
+            ROCK_PAPER_SCISSORS
+        ))
+        .unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (9, "a single moves line")
+        );
+        // The letters and rules may come before the moves they stand for.
+        let mut reordered: Vec<&str> = ROCK_PAPER_SCISSORS_LIZARD_SPOCK.lines().collect();
+        let moves = reordered.remove(1);
+        reordered.push(moves);
+        assert_eq!(
+            Game::parse(&reordered.join("\n")).as_ref(),
+            Ok(Game::rock_paper_scissors_lizard_spock())
+        );
+    }
+}
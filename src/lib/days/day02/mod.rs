@@ -0,0 +1,113 @@
+use crate::day::Day;
+use crate::parse::ParseError;
+use crate::random::Rng;
+use game::{Game, Reading, Round};
+
+pub mod game;
+pub mod reference;
+pub mod strategy;
+
+type Solution = u32;
+pub type ParseOutput = Vec<Round>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    Game::rock_paper_scissors().parse_guide(file)
+}
+
+/// `size` rounds of random moves.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&['A', 'B', 'C']),
+                rng.choose(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect()
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    Game::rock_paper_scissors()
+        .total(parse_output, Reading::Move)
+        .unwrap()
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    Game::rock_paper_scissors()
+        .total(parse_output, Reading::Outcome)
+        .unwrap()
+}
+
+pub struct Day02;
+
+impl Day for Day02 {
+    const DAY: u8 = 2;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Solution {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 15);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 12);
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        let error = parse(
+            "A Y
+B W",
+        )
+        .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.expected, "X, Y or Z");
+        assert!(parse("AX").is_err());
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day02, 0..20, 20);
+    }
+}
@@ -0,0 +1,60 @@
+use super::{ParseOutput, Solution};
+
+const ROCK: u32 = 1;
+const PAPER: u32 = 2;
+const SCISSORS: u32 = 3;
+const LOOSE: u32 = 1;
+const DRAW: u32 = 2;
+const WIN: u32 = 3;
+
+/// The shape or outcome a letter stands for, by its place in the alphabet.
+fn value(letter: char) -> u32 {
+    match letter {
+        'A' | 'X' => 1,
+        'B' | 'Y' => 2,
+        _ => 3,
+    }
+}
+
+/// Which shape beats which, straight from the puzzle.
+const BEATS: [(u32, u32); 3] = [(ROCK, SCISSORS), (SCISSORS, PAPER), (PAPER, ROCK)];
+
+fn outcome(opponent: u32, me: u32) -> u32 {
+    if me == opponent {
+        DRAW
+    } else if BEATS.contains(&(me, opponent)) {
+        WIN
+    } else {
+        LOOSE
+    }
+}
+
+fn score(opponent: u32, me: u32) -> u32 {
+    me + match outcome(opponent, me) {
+        WIN => 6,
+        DRAW => 3,
+        _ => 0,
+    }
+}
+
+pub fn part_1(rounds: &ParseOutput) -> Solution {
+    rounds
+        .iter()
+        .map(|&(opponent, me)| score(value(opponent), value(me)))
+        .sum()
+}
+
+/// Tries every shape until one ends the round the way the second column says.
+pub fn part_2(rounds: &ParseOutput) -> Solution {
+    rounds
+        .iter()
+        .map(|&(opponent, wanted)| {
+            let (opponent, wanted) = (value(opponent), value(wanted));
+            let me = [ROCK, PAPER, SCISSORS]
+                .into_iter()
+                .find(|me| outcome(opponent, *me) == wanted)
+                .unwrap();
+            score(opponent, me)
+        })
+        .sum()
+}
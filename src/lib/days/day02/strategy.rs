@@ -0,0 +1,301 @@
+use super::game::{Game, Round};
+use crate::runner::align_columns;
+use std::cmp::{Ordering, Reverse};
+
+/// The column of every row of `weights` such that no two rows share a column and the weights
+/// add up to the most, by the Hungarian algorithm in O(n³). Rows are added one at a time along
+/// the shortest augmenting path, with potentials `u` and `v` keeping the reduced costs
+/// non-negative. Columns and rows count from 1 inside, 0 standing for no row.
+fn max_assignment(weights: &[Vec<u32>]) -> Vec<usize> {
+    let n = weights.len();
+    let (mut u, mut v) = (vec![0i64; n + 1], vec![0i64; n + 1]);
+    let mut row_of = vec![0; n + 1];
+    let mut way = vec![0; n + 1];
+    for row in 1..=n {
+        row_of[0] = row;
+        let mut column = 0;
+        let mut min = vec![i64::MAX; n + 1];
+        let mut used = vec![false; n + 1];
+        while row_of[column] != 0 {
+            used[column] = true;
+            let r = row_of[column];
+            let (mut delta, mut next) = (i64::MAX, 0);
+            for j in (1..=n).filter(|&j| !used[j]) {
+                let cost = -(weights[r - 1][j - 1] as i64) - u[r] - v[j];
+                if cost < min[j] {
+                    min[j] = cost;
+                    way[j] = column;
+                }
+                if min[j] < delta {
+                    delta = min[j];
+                    next = j;
+                }
+            }
+            for j in 0..=n {
+                if used[j] {
+                    u[row_of[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min[j] -= delta;
+                }
+            }
+            column = next;
+        }
+        while column != 0 {
+            let previous = way[column];
+            row_of[column] = row_of[previous];
+            column = previous;
+        }
+    }
+    let mut assignment = vec![0; n];
+    for (column, &row) in row_of.iter().enumerate().skip(1) {
+        assignment[row - 1] = column - 1;
+    }
+    assignment
+}
+
+/// The letters of the second column, one per move as in `Game::me`, that score the most on
+/// `guide` when read as moves, along with that score. Rounds with other letters do not count.
+/// Each letter adds the same to the score whatever the other letters stand for, so this is an
+/// assignment of letters to moves.
+pub fn best_mapping(game: &Game, guide: &[Round]) -> (Vec<char>, u32) {
+    let n = game.moves().len();
+    let mut counts = vec![vec![0; n]; n];
+    for &(opponent, me) in guide {
+        let opponent = game.opponent().iter().position(|&c| c == opponent);
+        let letter = game.me().iter().position(|&c| c == me);
+        if let (Some(opponent), Some(letter)) = (opponent, letter) {
+            counts[opponent][letter] += 1;
+        }
+    }
+
+    let weights: Vec<Vec<u32>> = (0..n)
+        .map(|letter| {
+            (0..n)
+                .map(|m| (0..n).map(|o| counts[o][letter] * game.score(o, m)).sum())
+                .collect()
+        })
+        .collect();
+    let moves = max_assignment(&weights);
+    let score = moves.iter().enumerate().map(|(l, &m)| weights[l][m]).sum();
+    let mut letters = vec![' '; n];
+    for (letter, &m) in moves.iter().enumerate() {
+        letters[m] = game.me()[letter];
+    }
+    (letters, score)
+}
+
+/// A table of the move every letter of `best_mapping` stands for, and the score.
+pub fn format_mapping(game: &Game, (letters, score): &(Vec<char>, u32)) -> String {
+    let mut rows = vec![["Letter", "Move"].map(String::from)];
+    for (name, letter) in game.moves().iter().zip(letters) {
+        rows.push([letter.to_string(), name.clone()]);
+    }
+    rows.push(["Score".into(), score.to_string()]);
+    align_columns(&rows)
+}
+
+/// How a guide did in a tournament, known by its name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Standing<'a> {
+    pub name: &'a str,
+    pub won: u32,
+    pub drawn: u32,
+    pub lost: u32,
+    pub score: u32,
+}
+
+impl Standing<'_> {
+    /// Three points a win and one a draw.
+    pub fn points(&self) -> u32 {
+        3 * self.won + self.drawn
+    }
+}
+
+/// Plays every named guide once against every other, each playing the moves of its second
+/// column and skipping the letters that are not moves.
+/// Matches last as long as the shorter guide and go to the guide scoring the most. Standings are
+/// sorted by points, then by score.
+pub fn tournament<'a>(game: &Game, guides: &[(&'a str, Vec<Round>)]) -> Vec<Standing<'a>> {
+    let moves: Vec<Vec<usize>> = guides
+        .iter()
+        .map(|(_, guide)| {
+            guide
+                .iter()
+                .filter_map(|(_, me)| game.me().iter().position(|c| c == me))
+                .collect()
+        })
+        .collect();
+    let mut standings: Vec<Standing> = guides
+        .iter()
+        .map(|&(name, _)| Standing {
+            name,
+            ..Standing::default()
+        })
+        .collect();
+    for a in 0..guides.len() {
+        for b in a + 1..guides.len() {
+            let (mut score_a, mut score_b) = (0, 0);
+            for (&move_a, &move_b) in moves[a].iter().zip(&moves[b]) {
+                score_a += game.score(move_b, move_a);
+                score_b += game.score(move_a, move_b);
+            }
+            standings[a].score += score_a;
+            standings[b].score += score_b;
+            match score_a.cmp(&score_b) {
+                Ordering::Greater => {
+                    standings[a].won += 1;
+                    standings[b].lost += 1;
+                }
+                Ordering::Less => {
+                    standings[a].lost += 1;
+                    standings[b].won += 1;
+                }
+                Ordering::Equal => {
+                    standings[a].drawn += 1;
+                    standings[b].drawn += 1;
+                }
+            }
+        }
+    }
+    let mut ranked: Vec<usize> = (0..standings.len()).collect();
+    ranked.sort_by_key(|&i| {
+        (
+            Reverse(standings[i].points()),
+            Reverse(standings[i].score),
+            i,
+        )
+    });
+    ranked.into_iter().map(|i| standings[i]).collect()
+}
+
+pub fn format_standings(standings: &[Standing]) -> String {
+    let mut rows = vec![["", "Guide", "W", "D", "L", "Points", "Score"].map(String::from)];
+    for (rank, s) in standings.iter().enumerate() {
+        rows.push([
+            (rank + 1).to_string(),
+            s.name.to_string(),
+            s.won.to_string(),
+            s.drawn.to_string(),
+            s.lost.to_string(),
+            s.points().to_string(),
+            s.score.to_string(),
+        ]);
+    }
+    align_columns(&rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day02::game::Reading;
+    use crate::days::day02::{parse, TEST_INPUT};
+    use crate::random::Rng;
+
+    /// Every order of `0..n`.
+    fn permutations(n: usize) -> Vec<Vec<usize>> {
+        if n == 0 {
+            return vec![Vec::new()];
+        }
+        let mut all = Vec::new();
+        for first in 0..n {
+            for rest in permutations(n - 1) {
+                let mut permutation = vec![first];
+                permutation.extend(rest.into_iter().map(|i| if i >= first { i + 1 } else { i }));
+                all.push(permutation);
+            }
+        }
+        all
+    }
+
+    #[test]
+    pub fn test_max_assignment() {
+        assert_eq!(max_assignment(&[]), Vec::<usize>::new());
+        assert_eq!(
+            max_assignment(&[vec![1, 9, 1], vec![9, 8, 1], vec![1, 1, 2]]),
+            vec![1, 0, 2]
+        );
+        let mut rng = Rng::new(0);
+        for _ in 0..50 {
+            let n = rng.range(1..7) as usize;
+            let weights: Vec<Vec<u32>> = (0..n)
+                .map(|_| (0..n).map(|_| rng.range(0..20) as u32).collect())
+                .collect();
+            let total = |columns: &[usize]| -> u32 {
+                columns
+                    .iter()
+                    .enumerate()
+                    .map(|(r, &c)| weights[r][c])
+                    .sum()
+            };
+            let assignment = max_assignment(&weights);
+            let mut sorted = assignment.clone();
+            sorted.sort_unstable();
+            assert_eq!(sorted, (0..n).collect::<Vec<_>>());
+            assert_eq!(
+                Some(total(&assignment)),
+                permutations(n).iter().map(|p| total(p)).max()
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_best_mapping() {
+        let mut game = Game::rock_paper_scissors().clone();
+        let guide = parse(TEST_INPUT).unwrap();
+        let (letters, score) = best_mapping(&game, &guide);
+        assert_eq!((letters.clone(), score), (vec!['Z', 'Y', 'X'], 24));
+        assert_eq!(
+            format_mapping(&game, &(letters.clone(), score)),
+            "Letter  Move\nZ       rock\nY       paper\nX       scissors\nScore   24\n"
+        );
+        game.set_me(letters).unwrap();
+        assert_eq!(game.total(&guide, Reading::Move), Some(24));
+
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let guide = game.parse_guide("A V\nA V\nB W\nC X").unwrap();
+        let best = permutations(5)
+            .into_iter()
+            .map(|moves| {
+                let mut game = game.clone();
+                game.set_me(
+                    moves
+                        .iter()
+                        .map(|&m| "VWXYZ".as_bytes()[m] as char)
+                        .collect(),
+                )
+                .unwrap();
+                game.total(&guide, Reading::Move).unwrap()
+            })
+            .max();
+        assert_eq!(Some(best_mapping(game, &guide).1), best);
+    }
+
+    #[test]
+    pub fn test_tournament() {
+        let game = Game::rock_paper_scissors();
+        let guides: Vec<(&str, Vec<Round>)> = [
+            ("rocks", "A X\nA X\nA X"),
+            ("papers", "A Y\nA Y"),
+            ("scissors", "A Z\nA Z\nA Z"),
+            ("cycle", "A X\nA Y\nA Z"),
+        ]
+        .iter()
+        .map(|(name, guide)| (*name, game.parse_guide(guide).unwrap()))
+        .collect();
+        let standings = tournament(game, &guides);
+        let order: Vec<(&str, u32)> = standings.iter().map(|s| (s.name, s.points())).collect();
+        assert_eq!(
+            order,
+            vec![("scissors", 6), ("papers", 6), ("cycle", 3), ("rocks", 3)]
+        );
+        assert_eq!(
+            standings.iter().map(|s| s.won).sum::<u32>(),
+            standings.iter().map(|s| s.lost).sum()
+        );
+
+        let table = format_standings(&standings);
+        assert!(table.lines().nth(1).unwrap().starts_with("1  scissors"));
+        assert_eq!(table.lines().count(), 5);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::day::Day;
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+use std::collections::HashSet;
+
+pub mod reference;
+
+type Solution = u32;
+
+pub type Rucksack = Vec<u32>;
+pub type ParseOutput = Vec<Rucksack>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    let rucksacks = source
+        .lines()
+        .map(
+            |rucksack: &str| match rucksack.find(|c: char| !c.is_ascii_alphabetic()) {
+                Some(i) => Err(source.error(&rucksack[i..], "a letter")),
+                None if rucksack.len() % 2 == 1 => Err(source.error(
+                    &rucksack[rucksack.len()..],
+                    "another item to split the rucksack in halves",
+                )),
+                None => Ok(rucksack.chars().map(match_chars_to_nums).collect()),
+            },
+        )
+        .collect::<Result<ParseOutput, _>>()?;
+    if rucksacks.len() % 3 != 0 {
+        return Err(source.error(
+            source.end(),
+            "another rucksack to complete the group of three",
+        ));
+    }
+    Ok(rucksacks)
+}
+
+/// Groups of three rucksacks, `size` lines in total rounded up. Every elf packs from a pool of
+/// items of its own, so the only item the compartments share is the one put in both on purpose,
+/// and the only one the whole group shares is the badge.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut file = String::new();
+    for _ in 0..size.div_ceil(3) {
+        rng.shuffle(&mut items);
+        let badge = items[0];
+        for pool in items[1..].chunks(17) {
+            let (shared, rest) = (pool[0], &pool[1..]);
+            let (first, second) = rest.split_at(1 + rng.below(rest.len() - 1));
+            let len = 2 + rng.below(15);
+            let mut compartments = [vec![shared], vec![shared, badge]];
+            for (compartment, pool) in compartments.iter_mut().zip([first, second]) {
+                while compartment.len() < len {
+                    compartment.push(*rng.choose(pool));
+                }
+                rng.shuffle(compartment);
+            }
+            file.extend(compartments.concat());
+            file.push('\n');
+        }
+    }
+    file
+}
+
+fn match_chars_to_nums(c: char) -> u32 {
+    if c.is_uppercase() {
+        c as u32 - 38
+    } else {
+        c as u32 - 96
+    }
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    let mut solution = 0;
+    let mut chars_in_map: HashSet<u32> = HashSet::new();
+    'outer: for rucksack in parse_output {
+        let compartment_1_len = rucksack.len() / 2;
+        let rucksack_iter = rucksack.iter().enumerate();
+        for (i, item) in rucksack_iter {
+            if i < compartment_1_len {
+                chars_in_map.insert(*item);
+                continue;
+            }
+            if chars_in_map.contains(item) {
+                solution += item;
+                chars_in_map.clear();
+                continue 'outer;
+            }
+        }
+    }
+    solution
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    let mut solution = 0;
+    let mut items_in_first_ruck: HashSet<u32> = HashSet::new();
+    let mut items_in_second_ruck: HashSet<u32> = HashSet::new();
+    'outer: for r in parse_output.chunks(3) {
+        for item in &r[0] {
+            items_in_first_ruck.insert(*item);
+        }
+        for item in &r[1] {
+            items_in_second_ruck.insert(*item);
+        }
+        for item in &r[2] {
+            if items_in_first_ruck.contains(item) && items_in_second_ruck.contains(item) {
+                solution += item;
+                items_in_first_ruck.clear();
+                items_in_second_ruck.clear();
+                continue 'outer;
+            }
+        }
+    }
+    solution
+}
+
+pub struct Day03;
+
+impl Day for Day03 {
+    const DAY: u8 = 3;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Solution {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 157);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 70);
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        let error = parse("abAB\nabc\ncdCD").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(
+            parse("abAB\ncdCD").unwrap_err().expected,
+            "another rucksack to complete the group of three"
+        );
+        assert_eq!(parse("abAB\nc1\ncdCD").unwrap_err().expected, "a letter");
+        assert!(parse("abAB\nbcBC\ncdCD").is_ok());
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day03, 0..20, 12);
+    }
+}
@@ -0,0 +1,28 @@
+use super::{ParseOutput, Solution};
+
+pub fn part_1(rucksacks: &ParseOutput) -> Solution {
+    rucksacks
+        .iter()
+        .map(|rucksack| {
+            let (first, second) = rucksack.split_at(rucksack.len() / 2);
+            first
+                .iter()
+                .find(|item| second.contains(item))
+                .copied()
+                .unwrap_or(0)
+        })
+        .sum()
+}
+
+pub fn part_2(rucksacks: &ParseOutput) -> Solution {
+    rucksacks
+        .chunks(3)
+        .map(|group| {
+            group[0]
+                .iter()
+                .find(|item| group[1..].iter().all(|r| r.contains(item)))
+                .copied()
+                .unwrap_or(0)
+        })
+        .sum()
+}
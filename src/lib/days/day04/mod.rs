@@ -0,0 +1,124 @@
+use crate::day::Day;
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+use std::ops::Range;
+
+pub mod reference;
+
+type Solution = u32;
+
+pub type ParseOutput = Vec<(Range<u32>, Range<u32>)>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    source
+        .lines()
+        .map(|pair: &str| {
+            let (first, second) = source.split_once(pair, ",")?;
+            let (s1, s2) = source.split_once(first, "-")?;
+            let (e1, e2) = source.split_once(second, "-")?;
+
+            Ok((
+                source.number(s1)?..source.number(s2)?,
+                source.number(e1)?..source.number(e2)?,
+            ))
+        })
+        .collect()
+}
+
+/// `size` pairs of section ranges between 1 and 99.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut range = || {
+        let start = rng.range(1..100);
+        (start, rng.range(start..100))
+    };
+    (0..size)
+        .map(|_| {
+            let ((s1, s2), (e1, e2)) = (range(), range());
+            format!("{}-{},{}-{}\n", s1, s2, e1, e2)
+        })
+        .collect()
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    let mut solution = 0;
+    for r in parse_output {
+        if (r.0.start >= r.1.start && r.0.end <= r.1.end)
+            || (r.1.start >= r.0.start && r.1.end <= r.0.end)
+        {
+            solution += 1;
+        }
+    }
+    solution
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    let mut solution = 0;
+    for r in parse_output {
+        if !(r.1.end < r.0.start || r.1.start > r.0.end) {
+            solution += 1;
+        }
+    }
+    solution
+}
+
+pub struct Day04;
+
+impl Day for Day04 {
+    const DAY: u8 = 4;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Solution {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 2);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 4);
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day04, 0..20, 20);
+    }
+}
@@ -0,0 +1,24 @@
+use super::{ParseOutput, Solution};
+use std::ops::Range;
+
+/// The sections of an assignment, whose ranges include their end.
+fn sections(range: &Range<u32>) -> Vec<u32> {
+    (range.start..=range.end).collect()
+}
+
+pub fn part_1(pairs: &ParseOutput) -> Solution {
+    pairs
+        .iter()
+        .filter(|(first, second)| {
+            let (first, second) = (sections(first), sections(second));
+            first.iter().all(|s| second.contains(s)) || second.iter().all(|s| first.contains(s))
+        })
+        .count() as Solution
+}
+
+pub fn part_2(pairs: &ParseOutput) -> Solution {
+    pairs
+        .iter()
+        .filter(|(first, second)| sections(first).iter().any(|s| sections(second).contains(s)))
+        .count() as Solution
+}
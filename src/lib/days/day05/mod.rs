@@ -0,0 +1,245 @@
+use crate::day::Day;
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+use std::collections::VecDeque;
+
+pub mod reference;
+
+type Solution = String;
+
+pub const NUM_STACKS: usize = 9;
+pub type ParseOutput = (Vec<VecDeque<char>>, Vec<(usize, usize, usize)>);
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let mut containers = Vec::new();
+    let mut container_moves = Vec::new();
+
+    let source = Source::new(file);
+    let (start_configuration, container_moves_unparsed) = match source.sections()[..] {
+        [configuration, moves] => (configuration, moves),
+        [configuration] => {
+            return Err(source.error(
+                &configuration[configuration.len()..],
+                "a blank line followed by moves",
+            ))
+        }
+        _ => return Err(source.error(source.end(), "a stack configuration and moves")),
+    };
+    for _i in 0..NUM_STACKS {
+        containers.push(VecDeque::new());
+    }
+
+    let rows: Vec<&str> = start_configuration.lines().collect();
+    let (labels, rows) = rows.split_last().unwrap_or((&"", &[]));
+    if let Some(i) = labels.find(|c: char| !c.is_ascii_digit() && c != ' ') {
+        return Err(source.error(&labels[i..], "a stack number"));
+    }
+    for row in rows {
+        if let Some(i) = row.find(|c: char| !c.is_ascii()) {
+            return Err(source.error(&row[i..], "a crate like '[A]' or spaces"));
+        }
+        for (stack_number, slot) in row.as_bytes().chunks(4).enumerate() {
+            let at = &row[stack_number * 4..];
+            match slot {
+                [b'[', c, b']'] | [b'[', c, b']', b' '] if c.is_ascii_alphabetic() => containers
+                    .get_mut(stack_number)
+                    .ok_or_else(|| source.error(at, format!("at most {} stacks", NUM_STACKS)))?
+                    .push_back(*c as char),
+                _ if slot.iter().all(|b| *b == b' ') => {}
+                _ => return Err(source.error(at, "a crate like '[A]' or spaces")),
+            }
+        }
+    }
+
+    let mut heights: Vec<usize> = containers.iter().map(VecDeque::len).collect();
+    let positive = |s: &str, max: usize| match source.number::<usize>(s)? {
+        n if n >= 1 && n <= max => Ok(n - 1),
+        _ => Err(source.error(s, format!("a number from 1 to {}", max))),
+    };
+    for container_move_unparsed in container_moves_unparsed.lines() {
+        let count = source.strip_prefix(container_move_unparsed, "move ")?;
+        let (count, from) = source.split_once(count, " from ")?;
+        let (from_at, to) = source.split_once(from, " to ")?;
+        let (from, to) = (
+            positive(from_at, NUM_STACKS)?,
+            positive(to.trim_end(), NUM_STACKS)?,
+        );
+        if heights[from] == 0 {
+            return Err(source.error(from_at, "a stack with crates on it"));
+        }
+        let count = positive(count, heights[from])?;
+        heights[from] -= count + 1;
+        heights[to] += count + 1;
+        container_moves.push((count, from, to))
+    }
+
+    Ok((containers, container_moves))
+}
+
+/// Full stacks of up to eight crates followed by `size` moves that never take more crates than
+/// a stack holds.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut heights: Vec<usize> = (0..NUM_STACKS).map(|_| 1 + rng.below(8)).collect();
+    let top = *heights.iter().max().unwrap();
+    let mut file = String::new();
+    for level in (0..top).rev() {
+        let row: Vec<String> = heights
+            .iter()
+            .map(|h| match *h > level {
+                true => format!("[{}]", (b'A' + rng.below(26) as u8) as char),
+                false => "   ".into(),
+            })
+            .collect();
+        file.push_str(row.join(" ").trim_end());
+        file.push('\n');
+    }
+    let numbers: Vec<String> = (1..=NUM_STACKS).map(|i| format!(" {} ", i)).collect();
+    file.push_str(numbers.join(" ").trim_end());
+    file.push_str("\n\n");
+
+    for _ in 0..size {
+        let full: Vec<usize> = (0..NUM_STACKS).filter(|i| heights[*i] > 0).collect();
+        let from = *rng.choose(&full);
+        let to = (from + 1 + rng.below(NUM_STACKS - 1)) % NUM_STACKS;
+        let count = 1 + rng.below(heights[from]);
+        heights[from] -= count;
+        heights[to] += count;
+        file.push_str(&format!("move {} from {} to {}\n", count, from + 1, to + 1));
+    }
+    file
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    let (mut stacks, container_moves) = parse_output.clone();
+
+    for container_move in container_moves {
+        let (container_count, from, to) = container_move;
+        for _i in 0..container_count + 1 {
+            let item = stacks.get_mut(from).unwrap().pop_front().unwrap();
+            stacks.get_mut(to).unwrap().push_front(item);
+        }
+    }
+
+    stacks
+        .into_iter()
+        .map(|s| {
+            if let Some(first) = s.front() {
+                *first
+            } else {
+                ' '
+            }
+        })
+        .filter(|c| *c != ' ')
+        .collect()
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    let (mut stacks, container_moves) = parse_output.clone();
+
+    let mut tmp_vec = Vec::new();
+    for container_move in container_moves {
+        let (container_count, from, to) = container_move;
+        for _ in 0..container_count + 1 {
+            tmp_vec.push(stacks.get_mut(from).unwrap().pop_front().unwrap());
+        }
+        for _ in 0..container_count + 1 {
+            stacks
+                .get_mut(to)
+                .unwrap()
+                .push_front(tmp_vec.pop().unwrap());
+        }
+    }
+
+    stacks
+        .into_iter()
+        .map(|s| {
+            if let Some(first) = s.front() {
+                *first
+            } else {
+                ' '
+            }
+        })
+        .filter(|c| *c != ' ')
+        .collect()
+}
+
+pub struct Day05;
+
+impl Day for Day05 {
+    const DAY: u8 = 5;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Solution {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), "CMZ");
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), "MCD");
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        let error = parse("[Z] [] [P]\n 1   2   3\n\nmove 1 from 3 to 2").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+        assert_eq!(error.expected, "a crate like '[A]' or spaces");
+        let error = parse("[Z]     [P]\n 1   2   3\n\nmove 1 from 2 to 1").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 13));
+        assert_eq!(error.expected, "a stack with crates on it");
+        let error = parse("[Z]\n[P]\n 1\n\nmove 3 from 1 to 2").unwrap_err();
+        assert_eq!(error.expected, "a number from 1 to 2");
+        assert_eq!(
+            parse("[Z]\n\nmove 1 from 1 to 2").unwrap_err().expected,
+            "a stack number"
+        );
+        assert_eq!(
+            parse("    [D]\n 1   2\n\nmove 1 from 2 to 1").unwrap().1,
+            [(0, 1, 0)]
+        );
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day05, 0..20, 10);
+    }
+}
@@ -0,0 +1,36 @@
+use super::{ParseOutput, Solution};
+
+/// The stacks bottom to top, so that crates are pushed and popped at the end.
+fn stacks((stacks, _): &ParseOutput) -> Vec<Vec<char>> {
+    stacks
+        .iter()
+        .map(|s| s.iter().rev().copied().collect())
+        .collect()
+}
+
+fn tops(stacks: &[Vec<char>]) -> Solution {
+    stacks.iter().filter_map(|s| s.last()).collect()
+}
+
+/// Moves the crates one at a time.
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    let mut stacks = stacks(parse_output);
+    for (count, from, to) in &parse_output.1 {
+        for _ in 0..=*count {
+            let item = stacks[*from].pop().unwrap();
+            stacks[*to].push(item);
+        }
+    }
+    tops(&stacks)
+}
+
+/// Moves the crates all at once, keeping their order.
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    let mut stacks = stacks(parse_output);
+    for (count, from, to) in &parse_output.1 {
+        let at = stacks[*from].len() - (count + 1);
+        let items = stacks[*from].split_off(at);
+        stacks[*to].extend(items);
+    }
+    tops(&stacks)
+}
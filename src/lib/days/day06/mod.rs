@@ -0,0 +1,151 @@
+use crate::day::Day;
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+
+pub mod reference;
+
+type Solution = u32;
+
+pub type ParseOutput = String;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    let signal = file.trim_end();
+    if let Some(i) = signal.find(|c: char| !c.is_ascii_lowercase()) {
+        return Err(source.error(&signal[i..], "a lowercase letter"));
+    }
+    if signal.len() < 4 {
+        return Err(source.error(&signal[signal.len()..], "at least 4 characters"));
+    }
+    Ok(signal.into())
+}
+
+/// `size` characters from only eight letters, too few for a message marker, then a marker of
+/// fourteen different letters so both parts have an answer, and a few more letters like in the
+/// puzzle inputs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut letters: Vec<char> = ('a'..='z').collect();
+    let mut signal: String = (0..size).map(|_| *rng.choose(&letters[..8])).collect();
+    rng.shuffle(&mut letters);
+    signal.extend(&letters[..14]);
+    signal.extend((0..rng.range(1..5)).map(|_| letters[rng.below(26)]));
+    signal.push('\n');
+    signal
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    let mut b: [u8; 4] = [0; 4];
+    let mut bytes = parse_output.bytes().enumerate();
+    b[0] = bytes.next().unwrap().1;
+    b[1] = bytes.next().unwrap().1;
+    b[2] = bytes.next().unwrap().1;
+    b[3] = bytes.next().unwrap().1;
+
+    for (i, byte) in bytes {
+        if b[0] == b[1]
+            || b[0] == b[2]
+            || b[0] == b[3]
+            || b[1] == b[2]
+            || b[1] == b[3]
+            || b[2] == b[3]
+        {
+            b[0] = b[1];
+            b[1] = b[2];
+            b[2] = b[3];
+            b[3] = byte;
+            continue;
+        }
+
+        return i as u32;
+    }
+
+    0
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    let mut b: [u8; 14] = [0; 14];
+    let mut bytes = parse_output.bytes().enumerate();
+
+    for (i, byte) in (&mut bytes).take(14) {
+        b[i] = byte;
+    }
+
+    'outer: for (i, byte) in bytes {
+        for u in 0..14 {
+            for v in (u + 1)..14 {
+                if b[u] == b[v] {
+                    for x in 0..13 {
+                        b[x] = b[x + 1];
+                    }
+                    b[13] = byte;
+                    continue 'outer;
+                }
+            }
+        }
+
+        return i as u32;
+    }
+
+    0
+}
+
+pub struct Day06;
+
+impl Day for Day06 {
+    const DAY: u8 = 6;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Solution {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 7);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 19);
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day06, 0..20, 30);
+    }
+}
@@ -0,0 +1,23 @@
+use super::{ParseOutput, Solution};
+
+/// The number of characters read up to the end of the first `len` different ones in a row.
+fn marker(signal: &str, len: usize) -> Solution {
+    let bytes = signal.as_bytes();
+    (len..=bytes.len())
+        .find(|end| {
+            let window = &bytes[end - len..*end];
+            window
+                .iter()
+                .enumerate()
+                .all(|(i, b)| !window[i + 1..].contains(b))
+        })
+        .unwrap_or(0) as Solution
+}
+
+pub fn part_1(signal: &ParseOutput) -> Solution {
+    marker(signal, 4)
+}
+
+pub fn part_2(signal: &ParseOutput) -> Solution {
+    marker(signal, 14)
+}
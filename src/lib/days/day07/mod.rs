@@ -0,0 +1,231 @@
+use crate::day::Day;
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+use std::cmp::min;
+
+pub mod reference;
+
+type Solution = u64;
+
+#[derive(Debug, Clone)]
+pub struct FileSystemEntry {
+    pub is_dir: bool,
+    pub file_name: String,
+    pub parent: usize,
+    pub size: u64,
+}
+
+impl FileSystemEntry {
+    pub fn new(is_dir: bool, name: String, size: u64, parent: usize) -> FileSystemEntry {
+        FileSystemEntry {
+            is_dir,
+            file_name: name,
+            parent,
+            size,
+        }
+    }
+}
+pub type ParseOutput = Vec<FileSystemEntry>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+const MAX_SIZE_P1: u64 = 100000;
+
+const MAX_SPACE_P2: u64 = 70000000;
+const MIN_REQUIRED_SPACE_P2: u64 = 30000000;
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let mut filesystem: Vec<FileSystemEntry> = Vec::new();
+    let mut current_dir_i: usize = 0;
+
+    let source = Source::new(file);
+    for l in source.lines() {
+        let command_elements: Vec<&str> = l.split_ascii_whitespace().collect();
+
+        match command_elements[..] {
+            ["$", "cd", ".."] => {
+                if let Some(current_dir) = filesystem.get_mut(current_dir_i) {
+                    current_dir_i = current_dir.parent;
+                }
+            }
+            ["$", "cd", dir] => {
+                filesystem.push(FileSystemEntry::new(true, dir.into(), 0, current_dir_i));
+                current_dir_i = filesystem.len() - 1;
+            }
+            ["$", "ls"] => {}
+            ["dir", _] => {}
+            [_, _] if filesystem.is_empty() => {
+                return Err(source.error(l, "a '$ cd' before the first listing"))
+            }
+            [size, file_name] => {
+                let file_size = source.number(size)?;
+                update_parent_file_sizes(&mut filesystem, current_dir_i, file_size);
+
+                filesystem.push(FileSystemEntry::new(
+                    false,
+                    file_name.into(),
+                    file_size,
+                    current_dir_i,
+                ));
+            }
+            _ => return Err(source.error(l, "a command, a directory or a file")),
+        }
+    }
+    if filesystem.is_empty() {
+        return Err(source.error(source.end(), "a '$ cd /' command"));
+    }
+
+    Ok(filesystem)
+}
+
+/// A terminal session exploring `size` files in a random tree of directories. The files add up
+/// to between 42 and 68 million, so part 2 always has to delete something that fits.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // Directories as (name, children, files), the root first.
+    let mut dirs = vec![(String::from("/"), Vec::new(), Vec::new())];
+    let weights: Vec<u64> = (0..size).map(|_| rng.range(1..1000) as u64).collect();
+    let total = rng.range(42_000_000..68_000_000) as u64;
+    let sum: u64 = weights.iter().sum();
+    for (i, weight) in weights.into_iter().enumerate() {
+        let mut dir = rng.below(dirs.len());
+        if rng.chance(0.3) {
+            let name = format!("{}{}", name(rng), i);
+            let child = dirs.len();
+            dirs[dir].1.push(child);
+            dirs.push((name, Vec::new(), Vec::new()));
+            dir = child;
+        }
+        let file = format!(
+            "{} {}.{}",
+            (total * weight / sum).max(1),
+            name(rng),
+            name(rng)
+        );
+        dirs[dir].2.push(file);
+    }
+
+    let mut file = String::new();
+    let mut stack = vec![Some(0)];
+    while let Some(dir) = stack.pop() {
+        let Some(dir) = dir else {
+            file.push_str("$ cd ..\n");
+            continue;
+        };
+        let (name, children, files) = &dirs[dir];
+        file.push_str(&format!("$ cd {}\n$ ls\n", name));
+        for child in children {
+            file.push_str(&format!("dir {}\n", dirs[*child].0));
+        }
+        for f in files {
+            file.push_str(f);
+            file.push('\n');
+        }
+        for child in children.iter().rev() {
+            stack.extend([None, Some(*child)]);
+        }
+    }
+    file
+}
+
+fn name(rng: &mut Rng) -> String {
+    (0..rng.range(1..6))
+        .map(|_| (b'a' + rng.below(26) as u8) as char)
+        .collect()
+}
+
+fn update_parent_file_sizes(filesystem: &mut [FileSystemEntry], current_dir_i: usize, size: u64) {
+    let mut cd_i = current_dir_i;
+    while cd_i != 0 {
+        let current_dir = filesystem.get_mut(cd_i).unwrap();
+        current_dir.size += size;
+        cd_i = current_dir.parent;
+    }
+    let current_dir = filesystem.get_mut(cd_i).unwrap();
+    current_dir.size += size;
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    parse_output
+        .iter()
+        .filter(|f| f.is_dir && f.size < MAX_SIZE_P1)
+        .map(|f| f.size)
+        .sum()
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    let unused_space = MAX_SPACE_P2 - parse_output.first().unwrap().size;
+    let mut solution = MAX_SPACE_P2;
+
+    for f in parse_output.iter().filter(|f| f.is_dir) {
+        if unused_space + f.size >= MIN_REQUIRED_SPACE_P2 {
+            solution = min(solution, f.size);
+        }
+    }
+
+    solution
+}
+
+pub struct Day07;
+
+impl Day for Day07 {
+    const DAY: u8 = 7;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Solution {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 95437);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 24933642);
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        assert_eq!(parse("").unwrap_err().expected, "a '$ cd /' command");
+        let error = parse("$ ls\n14848514 b.txt").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day07, 0..20, 30);
+    }
+}
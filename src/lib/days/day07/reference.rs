@@ -0,0 +1,40 @@
+use super::{ParseOutput, Solution, MAX_SIZE_P1, MAX_SPACE_P2, MIN_REQUIRED_SPACE_P2};
+
+/// The size of every directory, adding up each file it contains at any depth.
+fn directory_sizes(filesystem: &ParseOutput) -> Vec<Solution> {
+    let contains = |dir: usize, mut entry: usize| loop {
+        entry = filesystem[entry].parent;
+        if entry == dir {
+            return true;
+        }
+        if entry == 0 {
+            return false;
+        }
+    };
+    (0..filesystem.len())
+        .filter(|dir| filesystem[*dir].is_dir)
+        .map(|dir| {
+            (0..filesystem.len())
+                .filter(|f| !filesystem[*f].is_dir && contains(dir, *f))
+                .map(|f| filesystem[f].size)
+                .sum()
+        })
+        .collect()
+}
+
+pub fn part_1(filesystem: &ParseOutput) -> Solution {
+    directory_sizes(filesystem)
+        .into_iter()
+        .filter(|size| *size < MAX_SIZE_P1)
+        .sum()
+}
+
+pub fn part_2(filesystem: &ParseOutput) -> Solution {
+    let sizes = directory_sizes(filesystem);
+    let free = MAX_SPACE_P2 - sizes[0];
+    sizes
+        .into_iter()
+        .filter(|size| free + size >= MIN_REQUIRED_SPACE_P2)
+        .min()
+        .unwrap_or(MAX_SPACE_P2)
+}
@@ -0,0 +1,133 @@
+use crate::day::Day;
+use crate::grid::{Grid, Pos};
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+
+pub mod reference;
+
+type Solution = u32;
+
+pub type ParseOutput = Grid<u32>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    Grid::parse(&source, file, "a digit", |_, c| c.to_digit(10))
+}
+
+/// A forest of `size` by `size` trees.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size).map(|_| *rng.choose(&DIGITS)).collect();
+            row + "\n"
+        })
+        .collect()
+}
+
+const DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    parse_output
+        .positions()
+        .filter(|pos| los(parse_output, *pos))
+        .count() as Solution
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    parse_output
+        .positions()
+        .map(|pos| score(parse_output, pos))
+        .max()
+        .unwrap_or(0)
+}
+
+/// The trees in the four directions from `(y, x)`, each starting next to it and leading to the edge.
+fn sight_lines(map: &ParseOutput, (y, x): Pos) -> [Vec<u32>; 4] {
+    let row = map.row(y);
+    let column: Vec<u32> = map.column(x).copied().collect();
+    [
+        row[..x].iter().rev().copied().collect(),
+        row[x + 1..].to_vec(),
+        column[..y].iter().rev().copied().collect(),
+        column[y + 1..].to_vec(),
+    ]
+}
+
+fn los(map: &ParseOutput, pos: Pos) -> bool {
+    let n = map[pos];
+    sight_lines(map, pos)
+        .iter()
+        .any(|line| line.iter().all(|c| *c < n))
+}
+
+fn score(map: &ParseOutput, pos: Pos) -> u32 {
+    let n = map[pos];
+    sight_lines(map, pos)
+        .iter()
+        .map(|line| match line.iter().position(|c| *c >= n) {
+            Some(i) => i as u32 + 1,
+            None => line.len() as u32,
+        })
+        .product()
+}
+
+pub struct Day08;
+
+impl Day for Day08 {
+    const DAY: u8 = 8;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Solution {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 21);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 8);
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day08, 0..20, 8);
+    }
+}
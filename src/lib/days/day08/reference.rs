@@ -0,0 +1,46 @@
+use super::{ParseOutput, Solution};
+
+const DIRECTIONS: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// The trees seen from `(y, x)` looking in direction `(dy, dx)`, nearest first.
+fn line(map: &ParseOutput, (y, x): (usize, usize), (dy, dx): (isize, isize)) -> Vec<u32> {
+    let mut trees = Vec::new();
+    let (mut y, mut x) = (y as isize + dy, x as isize + dx);
+    while let Some(tree) = map.get_signed(y, x) {
+        trees.push(*tree);
+        y += dy;
+        x += dx;
+    }
+    trees
+}
+
+pub fn part_1(map: &ParseOutput) -> Solution {
+    map.positions()
+        .filter(|pos| {
+            DIRECTIONS
+                .iter()
+                .any(|d| line(map, *pos, *d).iter().all(|t| *t < map[*pos]))
+        })
+        .count() as Solution
+}
+
+pub fn part_2(map: &ParseOutput) -> Solution {
+    map.positions()
+        .map(|pos| {
+            DIRECTIONS
+                .iter()
+                .map(|d| {
+                    let mut seen = 0;
+                    for tree in line(map, pos, *d) {
+                        seen += 1;
+                        if tree >= map[pos] {
+                            break;
+                        }
+                    }
+                    seen
+                })
+                .product()
+        })
+        .max()
+        .unwrap_or(0)
+}
@@ -0,0 +1,180 @@
+use crate::animation::{Cell, Frames, Viewport};
+use crate::day::Day;
+use crate::parse::{ParseError, Source};
+use crate::point::{Direction, Point2};
+use crate::random::Rng;
+use std::collections::HashSet;
+
+pub mod reference;
+
+type Solution = i32;
+
+pub type Move = (Direction, i32);
+pub type ParseOutput = Vec<Move>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    source
+        .lines()
+        .map(|l| {
+            let (direction, length) = source.split_once(l, " ")?;
+            let length = source.number(length)?;
+            match direction {
+                "U" => Ok((Direction::Up, length)),
+                "D" => Ok((Direction::Down, length)),
+                "L" => Ok((Direction::Left, length)),
+                "R" => Ok((Direction::Right, length)),
+                _ => Err(source.error(direction, "U, D, L or R")),
+            }
+        })
+        .collect()
+}
+
+/// `size` moves of one to twenty steps.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&['U', 'D', 'L', 'R']),
+                rng.range(1..21)
+            )
+        })
+        .collect()
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    pull_rope(parse_output, 2, |_, _| true)
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    pull_rope(parse_output, 10, |_, _| true)
+}
+
+/// Moves a rope of `knots` knots, calling `on_step` after every step of the head and stopping
+/// early once it returns false.
+fn pull_rope<F: FnMut(&[Point2], &HashSet<Point2>) -> bool>(
+    parse_output: &ParseOutput,
+    knots: usize,
+    mut on_step: F,
+) -> Solution {
+    let mut parts = vec![Point2::ORIGIN; knots];
+    let mut visited_map = HashSet::with_capacity(10000);
+    visited_map.insert(Point2::ORIGIN);
+
+    for (direction, l) in parse_output {
+        for _ in 0..*l {
+            parts[0] += direction.offset();
+            move_tails(&mut parts, &mut visited_map);
+            if !on_step(&parts, &visited_map) {
+                return visited_map.len() as i32;
+            }
+        }
+    }
+
+    visited_map.len() as i32
+}
+
+pub fn animate(parse_output: &ParseOutput, frames: &mut dyn Frames) {
+    let head_path = parse_output
+        .iter()
+        .scan(Point2::ORIGIN, |head, (direction, l)| {
+            *head += direction.offset() * *l;
+            Some((*head).into())
+        });
+    let bounds = Viewport::bounding(head_path.chain([(0, 0)])).unwrap();
+    let mut steps = 0;
+    pull_rope(parse_output, 10, |parts, visited_map| {
+        steps += 1;
+        let caption = format!("step {}, {} visited by the tail", steps, visited_map.len());
+        frames.frame(&bounds, parts[0].into(), &caption, &mut |y, x| {
+            let point = Point2::new(y, x);
+            match parts.iter().position(|p| *p == point) {
+                Some(0) => Cell::Actor,
+                Some(i) => Cell::Marker(char::from_digit(i as u32, 10).unwrap()),
+                None if visited_map.contains(&point) => Cell::Marker('#'),
+                None => Cell::Empty,
+            }
+        })
+    });
+}
+
+fn move_tails(parts: &mut [Point2], visited_map: &mut HashSet<Point2>) {
+    for i in 1..parts.len() {
+        parts[i] = move_tail(parts[i - 1], parts[i]);
+    }
+    visited_map.insert(parts[parts.len() - 1]);
+}
+
+fn move_tail(head: Point2, tail: Point2) -> Point2 {
+    match head.chebyshev(tail) {
+        0 | 1 => tail,
+        _ => tail + (head - tail).signum(),
+    }
+}
+
+pub struct Day09;
+
+impl Day for Day09 {
+    const DAY: u8 = 9;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+    const ANIMATED: bool = true;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Solution {
+        part_2(input)
+    }
+
+    fn animate(input: &ParseOutput, _params: &(), frames: &mut dyn Frames) {
+        animate(input, frames)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 13);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 1);
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day09, 0..20, 20);
+    }
+}
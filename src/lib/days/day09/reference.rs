@@ -0,0 +1,38 @@
+use super::{ParseOutput, Solution};
+use crate::point::Direction;
+
+/// Moves the head one step at a time and lets every knot catch up with the one in front of it,
+/// keeping a sorted list of the places the tail has been.
+fn tail_visits(moves: &ParseOutput, knots: usize) -> Solution {
+    let mut rope = vec![(0, 0); knots];
+    let mut visited = vec![(0, 0)];
+    for (direction, steps) in moves {
+        for _ in 0..*steps {
+            match direction {
+                Direction::Up => rope[0].0 -= 1,
+                Direction::Down => rope[0].0 += 1,
+                Direction::Left => rope[0].1 -= 1,
+                Direction::Right => rope[0].1 += 1,
+            }
+            for i in 1..knots {
+                let (dy, dx): (i32, i32) = (rope[i - 1].0 - rope[i].0, rope[i - 1].1 - rope[i].1);
+                if dy.abs() > 1 || dx.abs() > 1 {
+                    rope[i].0 += dy.signum();
+                    rope[i].1 += dx.signum();
+                }
+            }
+            if let Err(i) = visited.binary_search(&rope[knots - 1]) {
+                visited.insert(i, rope[knots - 1]);
+            }
+        }
+    }
+    visited.len() as Solution
+}
+
+pub fn part_1(moves: &ParseOutput) -> Solution {
+    tail_visits(moves, 2)
+}
+
+pub fn part_2(moves: &ParseOutput) -> Solution {
+    tail_visits(moves, 10)
+}
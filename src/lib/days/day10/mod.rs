@@ -1,5 +1,8 @@
-#![feature(test)]
-#![feature(drain_filter)]
+use crate::day::Day;
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+
+pub mod reference;
 
 type Solution = i32;
 #[derive(Debug, Clone)]
@@ -9,23 +12,51 @@ pub enum Instruction {
 }
 pub type ParseOutput = Vec<(usize, Instruction)>;
 const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
 
-pub fn parse(file: &str) -> ParseOutput {
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
     let mut cycle = 0;
-    file.lines()
+    let program: ParseOutput = source
+        .lines()
         .map(|l| {
-            if let Some((_, length)) = l.split_once(' ') {
+            if let Some(length) = l.strip_prefix("addx ") {
                 cycle += 2;
-                (cycle, Instruction::Add(length.parse().unwrap()))
-            } else {
+                Ok((cycle, Instruction::Add(source.number(length)?)))
+            } else if l == "noop" {
                 cycle += 1;
-                (cycle, Instruction::Noop)
+                Ok((cycle, Instruction::Noop))
+            } else {
+                Err(source.error(l, "'noop' or 'addx'"))
             }
         })
-        .collect()
+        .collect::<Result<_, _>>()?;
+    if program.is_empty() {
+        return Err(source.error(source.end(), "'noop' or 'addx'"));
+    }
+    Ok(program)
 }
 
-fn part_1(parse_output: &ParseOutput) -> Solution {
+/// A program running exactly the 240 cycles the screen has, so `size` is ignored. The register
+/// jumps to random columns around the screen.
+pub fn generate(rng: &mut Rng, _size: usize) -> String {
+    let (mut cycle, mut x) = (0, 1);
+    let mut file = String::new();
+    while cycle < 240 {
+        let target = rng.range(-1..41);
+        if cycle + 2 > 240 || target == x || rng.chance(0.3) {
+            file.push_str("noop\n");
+            cycle += 1;
+        } else {
+            file.push_str(&format!("addx {}\n", target - x));
+            x = target;
+            cycle += 2;
+        }
+    }
+    file
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
     let mut solution = 0;
     let cycle_distance = 40;
     let mut check_cycle = 19;
@@ -53,7 +84,7 @@ fn part_1(parse_output: &ParseOutput) -> Solution {
     solution
 }
 
-fn part_2(parse_output: &ParseOutput) -> String {
+pub fn part_2(parse_output: &ParseOutput) -> String {
     let mut solution: Vec<char> = Vec::new();
     solution.push('\n');
     let width = 40;
@@ -75,7 +106,7 @@ fn part_2(parse_output: &ParseOutput) -> String {
         let pixel = cycle % 40;
 
         match current_signal - pixel as i32 {
-            -1 | 0 | 1 => {
+            -1..=1 => {
                 solution.push('#');
             }
             _ => solution.push('.'),
@@ -88,28 +119,56 @@ fn part_2(parse_output: &ParseOutput) -> String {
     solution.into_iter().collect()
 }
 
-fn main() {
-    let parse_output = parse(MAIN_INPUT);
-    println!("Solution to part 1 is {}", part_1(&parse_output));
-    println!("Solution to part 2 is {}", part_2(&parse_output));
+pub struct Day10;
+
+impl Day for Day10 {
+    const DAY: u8 = 10;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = String;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> String {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<String> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    extern crate test;
     use super::*;
-    use test::{black_box, Bencher};
-    const TEST_INPUT: &str = include_str!("test_input");
 
     #[test]
     pub fn test_part_1() {
-        let parse_output = parse(TEST_INPUT);
+        let parse_output = parse(TEST_INPUT).unwrap();
         assert_eq!(part_1(&parse_output), 13140);
     }
 
     #[test]
     pub fn test_part_2() {
-        let parse_output = parse(TEST_INPUT);
+        let parse_output = parse(TEST_INPUT).unwrap();
         let test_2 = "\n\
 ##..##..##..##..##..##..##..##..##..##..\n\
 ###...###...###...###...###...###...###.\n\
@@ -120,34 +179,16 @@ mod tests {
         assert_eq!(part_2(&parse_output), test_2);
     }
 
-    #[bench]
-    fn bench_parse(b: &mut Bencher) {
-        b.iter(|| {
-            let _ = parse(MAIN_INPUT);
-        });
-    }
-
-    #[bench]
-    fn bench_part_1(b: &mut Bencher) {
-        let parse_output = parse(MAIN_INPUT);
-        b.iter(move || {
-            assert_eq!(part_1(black_box(&parse_output)), 15360);
-        });
+    #[test]
+    pub fn test_parse_errors() {
+        assert_eq!(parse("").unwrap_err().line, 1);
+        let parse_output = parse("noop").unwrap();
+        assert_eq!(part_1(&parse_output), 0);
+        part_2(&parse_output);
     }
 
-    #[bench]
-    fn bench_part_2(b: &mut Bencher) {
-        let parse_output = parse(MAIN_INPUT);
-        let solution_2 = "\n\
-###..#..#.#....#..#...##..##..####..##..\n\
-#..#.#..#.#....#..#....#.#..#....#.#..#.\n\
-#..#.####.#....####....#.#......#..#..#.\n\
-###..#..#.#....#..#....#.#.##..#...####.\n\
-#....#..#.#....#..#.#..#.#..#.#....#..#.\n\
-#....#..#.####.#..#..##...###.####.#..#.\n";
-
-        b.iter(|| {
-            assert_eq!(part_2(black_box(&parse_output)), solution_2);
-        });
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day10, 0..20, 1);
     }
 }
@@ -0,0 +1,38 @@
+use super::{Instruction, ParseOutput, Solution};
+
+/// The value of the register during every cycle, the first one at index 0.
+fn register(program: &ParseOutput) -> Vec<i32> {
+    let mut x = 1;
+    let mut cycles = Vec::new();
+    for (_, instruction) in program {
+        match instruction {
+            Instruction::Noop => cycles.push(x),
+            Instruction::Add(n) => {
+                cycles.extend([x, x]);
+                x += n;
+            }
+        }
+    }
+    cycles
+}
+
+pub fn part_1(program: &ParseOutput) -> Solution {
+    let cycles = register(program);
+    [20, 60, 100, 140, 180, 220]
+        .iter()
+        .filter(|c| **c <= cycles.len())
+        .map(|c| *c as i32 * cycles[c - 1])
+        .sum()
+}
+
+pub fn part_2(program: &ParseOutput) -> String {
+    let mut screen = String::from("\n");
+    for (cycle, x) in register(program).into_iter().enumerate() {
+        let pixel = (cycle % 40) as i32;
+        screen.push(if (x - pixel).abs() <= 1 { '#' } else { '.' });
+        if pixel == 39 {
+            screen.push('\n');
+        }
+    }
+    screen
+}
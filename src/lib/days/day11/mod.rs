@@ -0,0 +1,244 @@
+use crate::combinator::{line_break, literal, number, parse_all, sections, Parser};
+use crate::day::Day;
+use crate::math::lcm_all;
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+
+pub mod reference;
+
+type Solution = u64;
+
+#[derive(Debug, Clone)]
+pub enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Pow,
+}
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    pub items: Vec<u64>,
+    pub operation: Operation,
+    pub divisible_test: u64,
+    pub true_monkey: usize,
+    pub false_monkey: usize,
+    pub inspections: u64,
+}
+pub type ParseOutput = Vec<Monkey>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+type Targets<'a> = [(usize, &'a str); 2];
+
+fn monkey<'a>() -> impl Parser<'a, (Monkey, Targets<'a>)> {
+    let items = literal("Starting items:").ignore_then(number().separated_by(literal(",")));
+    let operation = literal("Operation: new = old").ignore_then(
+        literal("*")
+            .then(literal("old"))
+            .to(Operation::Pow)
+            .or(literal("*").ignore_then(number()).map(Operation::Multiply))
+            .or(literal("+").ignore_then(number()).map(Operation::Add)),
+    );
+    let divisible_test = literal("Test: divisible by").ignore_then(number().try_map(|d| match d {
+        0 => Err("a divisor other than 0".to_string()),
+        d => Ok(d),
+    }));
+    let throw = |branch| {
+        literal(branch)
+            .ignore_then(literal("throw to monkey"))
+            .ignore_then(number().spanned())
+    };
+    literal("Monkey")
+        .ignore_then(number::<usize>())
+        .ignore_then(literal(":"))
+        .ignore_then(line_break())
+        .ignore_then(items)
+        .then_ignore(line_break())
+        .then(operation)
+        .then_ignore(line_break())
+        .then(divisible_test)
+        .then_ignore(line_break())
+        .then(throw("If true:"))
+        .then_ignore(line_break())
+        .then(throw("If false:"))
+        .map(|((((items, operation), divisible_test), t), f)| {
+            let monkey = Monkey {
+                items,
+                operation,
+                divisible_test,
+                true_monkey: t.0,
+                false_monkey: f.0,
+                inspections: 0,
+            };
+            (monkey, [t, f])
+        })
+}
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    let (monkeys, targets): (ParseOutput, Vec<Targets>) =
+        parse_all(&source, sections(monkey()))?.into_iter().unzip();
+
+    match targets
+        .into_iter()
+        .flatten()
+        .find(|(t, _)| *t >= monkeys.len())
+    {
+        Some((_, n)) => Err(source.error(n, format!("a monkey below {}", monkeys.len()))),
+        None => Ok(monkeys),
+    }
+}
+
+/// `size` monkeys, at least two, testing distinct primes while there are enough of them.
+/// Multiplying by at most 3 and dividing by 3 never raises a worry level in part 1, and the
+/// monkey squaring levels only squares the items it starts with, as no monkey throws to it.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.max(2);
+    let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+    rng.shuffle(&mut primes);
+    let square = (count >= 4).then(|| rng.below(count));
+    let targets: Vec<usize> = (0..count).filter(|m| Some(*m) != square).collect();
+
+    let monkeys: Vec<String> = (0..count)
+        .map(|i| {
+            let items: Vec<String> = (0..rng.range(1..7))
+                .map(|_| rng.range(50..100).to_string())
+                .collect();
+            let operation = match rng.below(2) {
+                _ if square == Some(i) => "* old".to_string(),
+                0 => format!("* {}", rng.range(2..4)),
+                _ => format!("+ {}", rng.range(1..9)),
+            };
+            let others: Vec<usize> = targets.iter().copied().filter(|t| *t != i).collect();
+            let if_true = rng.below(others.len());
+            let if_false = (if_true + 1 + rng.below(others.len().max(2) - 1)) % others.len();
+            format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = old {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                i,
+                items.join(", "),
+                operation,
+                primes[i % primes.len()],
+                others[if_true],
+                others[if_false]
+            )
+        })
+        .collect();
+    monkeys.join("\n")
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    monkey_throws(parse_output.clone(), 20, |item_level| item_level / 3)
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    let relief = lcm_all(parse_output.iter().map(|m| m.divisible_test));
+
+    monkey_throws(parse_output.clone(), 10000, |item_level| {
+        item_level % relief
+    })
+}
+
+fn monkey_throws<F: FnMut(u64) -> u64>(
+    mut monkeys: ParseOutput,
+    count: usize,
+    mut calm_down: F,
+) -> u64 {
+    let mut throws = Vec::new();
+    for _ in 0..count {
+        for i in 0..monkeys.len() {
+            let monkey = &mut monkeys[i];
+            for item in monkey.items.drain(..) {
+                monkey.inspections += 1;
+                let stress_level = calm_down(get_stress_level(item, &monkey.operation));
+                if stress_level.is_multiple_of(monkey.divisible_test) {
+                    throws.push((monkey.true_monkey, stress_level));
+                } else {
+                    throws.push((monkey.false_monkey, stress_level))
+                }
+            }
+            for (m_i, item) in throws.drain(..) {
+                monkeys[m_i].items.push(item);
+            }
+        }
+    }
+
+    let mut inspections: Vec<u64> = monkeys.into_iter().map(|m| m.inspections).collect();
+    inspections.sort();
+    inspections.iter().rev().take(2).product()
+}
+
+fn get_stress_level(item: u64, operation: &Operation) -> u64 {
+    match operation {
+        Operation::Pow => item.checked_mul(item),
+        Operation::Add(n) => item.checked_add(*n),
+        Operation::Multiply(n) => item.checked_mul(*n),
+    }
+    .unwrap_or_else(|| panic!("stress level of {} overflows u64", item))
+}
+
+pub struct Day11;
+
+impl Day for Day11 {
+    const DAY: u8 = 11;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Solution {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 10605);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 2713310158);
+    }
+
+    #[test]
+    pub fn test_parse_error() {
+        let file = TEST_INPUT.replacen("divisible by 23", "divisible by x", 1);
+        let error = parse(&file).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 22));
+        let error = parse(&TEST_INPUT.replacen("monkey 2", "monkey 9", 1)).unwrap_err();
+        assert_eq!(error.expected, "a monkey below 4");
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day11, 0..10, 5);
+    }
+}
@@ -0,0 +1,46 @@
+use super::{Monkey, Operation, ParseOutput, Solution};
+
+/// Plays the rounds, keeping worry levels below the product of the different divisors when there
+/// is no relief. Taking the remainder of that product keeps every divisibility test the same.
+fn monkey_business(monkeys: &ParseOutput, rounds: usize, relief: bool) -> Solution {
+    let mut monkeys: Vec<Monkey> = monkeys.to_vec();
+    let mut divisors: Vec<u64> = monkeys.iter().map(|m| m.divisible_test).collect();
+    divisors.sort();
+    divisors.dedup();
+    let product: u64 = divisors.iter().product();
+    let mut inspections = vec![0; monkeys.len()];
+    for _ in 0..rounds {
+        for i in 0..monkeys.len() {
+            let items = std::mem::take(&mut monkeys[i].items);
+            for item in items {
+                inspections[i] += 1;
+                let mut worry = match monkeys[i].operation {
+                    Operation::Add(n) => item + n,
+                    Operation::Multiply(n) => item * n,
+                    Operation::Pow => item * item,
+                };
+                if relief {
+                    worry /= 3;
+                } else {
+                    worry %= product;
+                }
+                let target = if worry % monkeys[i].divisible_test == 0 {
+                    monkeys[i].true_monkey
+                } else {
+                    monkeys[i].false_monkey
+                };
+                monkeys[target].items.push(worry);
+            }
+        }
+    }
+    inspections.sort();
+    inspections.iter().rev().take(2).product()
+}
+
+pub fn part_1(monkeys: &ParseOutput) -> Solution {
+    monkey_business(monkeys, 20, true)
+}
+
+pub fn part_2(monkeys: &ParseOutput) -> Solution {
+    monkey_business(monkeys, 10000, false)
+}
@@ -0,0 +1,175 @@
+use crate::day::Day;
+use crate::grid::{Grid, Pos};
+use crate::math::manhattan_distance;
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+use crate::search::{self, Graph};
+
+pub mod reference;
+
+type Solution = u32;
+type CostType = i8;
+type ParseOutput = (Grid<CostType>, Pos, Pos);
+
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    let mut start = None;
+    let mut end = None;
+    let grid = Grid::parse(
+        &source,
+        file,
+        "a height from 'a' to 'z', 'S' or 'E'",
+        |pos, character| match character {
+            'S' => {
+                start = Some(pos);
+                Some(0)
+            }
+            'E' => {
+                end = Some(pos);
+                Some(25)
+            }
+            'a'..='z' => Some(character as CostType - 97),
+            _ => None,
+        },
+    )?;
+
+    match (start, end) {
+        (Some(start), Some(end)) => Ok((grid, start, end)),
+        (None, _) => Err(source.error(source.end(), "a start 'S'")),
+        (_, None) => Err(source.error(source.end(), "an end 'E'")),
+    }
+}
+
+/// A heightmap `size` squares wide, at least 26, and a quarter as high. One random row climbs
+/// evenly from the start on the left to the end on the right, so the end can always be reached.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(26);
+    let height = (width / 4).max(3);
+    let path = rng.below(height);
+    let mut file = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            file.push(match (y == path, x) {
+                (true, 0) => 'S',
+                (true, x) if x == width - 1 => 'E',
+                (true, x) => (b'a' + (x * 25 / (width - 1)) as u8) as char,
+                (false, _) if rng.chance(0.4) => 'a',
+                (false, _) => (b'a' + rng.below(26) as u8) as char,
+            });
+        }
+        file.push('\n');
+    }
+    file
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    let (grid, start, end) = parse_output;
+    fastest_path(grid, [*start], *end)
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    let (grid, _, end) = parse_output;
+    let starts = grid
+        .iter()
+        .filter(|(_, height)| **height == 0)
+        .map(|(pos, _)| pos);
+    fastest_path(grid, starts, *end)
+}
+
+/// Every step goes up at most one level, and the end is always at least its distance away.
+struct Hill<'a> {
+    heights: &'a Grid<CostType>,
+    end: Pos,
+}
+
+impl Graph for Hill<'_> {
+    type Node = Pos;
+    type Cost = u32;
+
+    fn neighbours(&self, pos: &Pos) -> Vec<(Pos, u32)> {
+        let height = self.heights[*pos];
+        self.heights
+            .neighbours_4(*pos)
+            .filter(|next| self.heights[*next] - height <= 1)
+            .map(|next| (next, 1))
+            .collect()
+    }
+
+    fn heuristic(&self, pos: &Pos) -> u32 {
+        manhattan_distance(*pos, self.end)
+    }
+}
+
+/// The number of steps from the nearest of `starts` to `end`, 0 if it can't be reached.
+fn fastest_path<S: IntoIterator<Item = Pos>>(
+    heights: &Grid<CostType>,
+    starts: S,
+    end: Pos,
+) -> Solution {
+    let hill = Hill { heights, end };
+    let search = search::astar(&hill, starts, |pos| *pos == end);
+    crate::debug!("visited {} fields", search.visited());
+    search.cost().unwrap_or(0)
+}
+
+pub struct Day12;
+
+impl Day for Day12 {
+    const DAY: u8 = 12;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Solution {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 31);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 29);
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day12, 0..10, 30);
+    }
+}
@@ -0,0 +1,41 @@
+use super::{ParseOutput, Solution};
+use crate::grid::Pos;
+use std::collections::VecDeque;
+
+/// Breadth first search from all `starts` at once, 0 if the end can not be reached.
+fn steps(parse_output: &ParseOutput, starts: Vec<Pos>) -> Solution {
+    let (heights, _, end) = parse_output;
+    let mut steps = heights.map(|_| None);
+    let mut queue = VecDeque::new();
+    for start in starts {
+        steps[start] = Some(0);
+        queue.push_back(start);
+    }
+    while let Some(pos) = queue.pop_front() {
+        let n = steps[pos].unwrap();
+        if pos == *end {
+            return n;
+        }
+        for next in heights.neighbours_4(pos) {
+            if steps[next].is_none() && heights[next] <= heights[pos] + 1 {
+                steps[next] = Some(n + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    0
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    steps(parse_output, vec![parse_output.1])
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    let (heights, _, _) = parse_output;
+    let lowest = heights
+        .iter()
+        .filter(|(_, height)| **height == 0)
+        .map(|(pos, _)| pos)
+        .collect();
+    steps(parse_output, lowest)
+}
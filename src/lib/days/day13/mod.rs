@@ -0,0 +1,168 @@
+pub mod package;
+pub mod reference;
+use crate::day::Day;
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+use package::Package;
+
+type Solution = u32;
+
+pub type ParseOutput = Vec<(Package, Package)>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    source
+        .sections()
+        .into_iter()
+        .map(
+            |pair| match pair.lines().map(str::trim).collect::<Vec<&str>>()[..] {
+                [p1, p2] => Ok((Package::parse(&source, p1)?, Package::parse(&source, p2)?)),
+                _ => Err(source.error(pair, "a pair of two packets")),
+            },
+        )
+        .collect()
+}
+
+/// `size` pairs of packets nested up to four lists deep. The second packet of a pair often
+/// starts like the first one, so the comparisons have to look past the first value.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let pairs: Vec<String> = (0..size)
+        .map(|_| {
+            let first = packet(rng, 0);
+            let second = match rng.chance(0.5) {
+                true => first[..first.len() / 2]
+                    .trim_end_matches([',', '['])
+                    .to_string(),
+                false => String::new(),
+            };
+            let second = match second.is_empty() {
+                true => packet(rng, 0),
+                false => close(&format!("{},{}", second, packet(rng, 1))),
+            };
+            format!("{}\n{}\n", first, second)
+        })
+        .collect();
+    pairs.join("\n")
+}
+
+fn packet(rng: &mut Rng, depth: usize) -> String {
+    let values: Vec<String> = (0..rng.below(5))
+        .map(|_| match depth < 4 && rng.chance(0.3) {
+            true => packet(rng, depth + 1),
+            false => rng.below(11).to_string(),
+        })
+        .collect();
+    format!("[{}]", values.join(","))
+}
+
+/// Adds the brackets needed to close every list still open at the end of `start`.
+fn close(start: &str) -> String {
+    let open = start.matches('[').count() - start.matches(']').count();
+    format!("{}{}", start, "]".repeat(open))
+}
+
+fn divider(p: &str) -> Package {
+    Package::parse(&Source::new(p), p).unwrap()
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    parse_output
+        .iter()
+        .enumerate()
+        .map(|(i, (p1, p2))| if p1 < p2 { i as u32 + 1 } else { 0 })
+        .sum()
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    let mut packages =
+        parse_output
+            .iter()
+            .fold(Vec::with_capacity(parse_output.len() * 2), |mut acc, p| {
+                let (p1, p2) = p;
+                acc.push(p1);
+                acc.push(p2);
+                acc
+            });
+    let (d1, d2) = (divider("[[2]]"), divider("[[6]]"));
+    packages.push(&d1);
+    packages.push(&d2);
+    // The sort is stable, so packets equal to a divider stay in front of it.
+    packages.sort();
+    packages.iter().enumerate().fold(1, |acc, (i, p)| {
+        if std::ptr::eq(*p, &d1) || std::ptr::eq(*p, &d2) {
+            acc * (i as u32 + 1)
+        } else {
+            acc
+        }
+    })
+}
+
+pub struct Day13;
+
+impl Day for Day13 {
+    const DAY: u8 = 13;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Solution {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 13);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 140);
+    }
+
+    #[test]
+    pub fn test_parse_error() {
+        let error = parse("[1,[2]\n[3]").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+        assert_eq!(error.expected, "',' or ']'");
+        let error = parse("[1]\n[2]\n[3]").unwrap_err();
+        assert_eq!(error.expected, "a pair of two packets");
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day13, 0..20, 10);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::parse::{ParseError, Source};
 use std::cmp::{min, Ordering};
 
 #[derive(Debug, Clone)]
@@ -16,7 +17,7 @@ impl PartialEq<Self> for Package {
 
 impl PartialOrd<Self> for Package {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(get_order(self, other))
+        Some(self.cmp(other))
     }
 }
 
@@ -52,38 +53,34 @@ fn get_order(p1: &Package, p2: &Package) -> Ordering {
 }
 
 impl Package {
-    pub fn new(p: String) -> Package {
-        if &p[0..1] == "[" {
-            let mut depth = 0;
-            let new_p: String = p
-                .chars()
-                .map(|c| {
-                    match c {
-                        ']' => depth -= 1,
-                        '[' => depth += 1,
-                        _ => {}
-                    }
-                    if c == ',' && depth == 1 {
-                        '|'
-                    } else {
-                        c
-                    }
-                })
-                .collect();
-            Package::List(
-                new_p[1..new_p.len() - 1]
-                    .split('|')
-                    .filter_map(|package| {
-                        if !package.is_empty() {
-                            Some(Package::new(package.into()))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect(),
-            )
+    pub fn parse<'a>(source: &Source<'a>, p: &'a str) -> Result<Package, ParseError> {
+        match Package::parse_prefix(source, p)? {
+            (package, "") => Ok(package),
+            (_, rest) => Err(source.error(rest, "end of line")),
+        }
+    }
+
+    fn parse_prefix<'a>(source: &Source<'a>, p: &'a str) -> Result<(Package, &'a str), ParseError> {
+        if let Some(mut rest) = p.strip_prefix('[') {
+            let mut list = Vec::new();
+            if let Some(rest) = rest.strip_prefix(']') {
+                return Ok((Package::List(list), rest));
+            }
+            loop {
+                let (package, r) = Package::parse_prefix(source, rest)?;
+                list.push(package);
+                if let Some(r) = r.strip_prefix(',') {
+                    rest = r;
+                } else if let Some(r) = r.strip_prefix(']') {
+                    return Ok((Package::List(list), r));
+                } else {
+                    return Err(source.error(r, "',' or ']'"));
+                }
+            }
         } else {
-            Package::Number(p.parse::<u32>().unwrap())
+            let end = p.find(|c: char| !c.is_ascii_digit()).unwrap_or(p.len());
+            let n = source.number(&p[..end])?;
+            Ok((Package::Number(n), &p[end..]))
         }
     }
 }
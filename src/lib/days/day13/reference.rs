@@ -0,0 +1,47 @@
+use super::package::Package;
+use super::{divider, ParseOutput, Solution};
+
+/// Whether `left` and `right` are in the right order, None if the puzzle rules can not tell.
+fn right_order(left: &Package, right: &Package) -> Option<bool> {
+    match (left, right) {
+        (Package::Number(l), Package::Number(r)) if l == r => None,
+        (Package::Number(l), Package::Number(r)) => Some(l < r),
+        (Package::Number(l), _) => right_order(&Package::List(vec![Package::Number(*l)]), right),
+        (_, Package::Number(r)) => right_order(left, &Package::List(vec![Package::Number(*r)])),
+        (Package::List(l), Package::List(r)) => {
+            for (l, r) in l.iter().zip(r) {
+                if let Some(order) = right_order(l, r) {
+                    return Some(order);
+                }
+            }
+            if l.len() == r.len() {
+                None
+            } else {
+                Some(l.len() < r.len())
+            }
+        }
+    }
+}
+
+pub fn part_1(pairs: &ParseOutput) -> Solution {
+    let mut sum = 0;
+    for (i, (left, right)) in pairs.iter().enumerate() {
+        if right_order(left, right) == Some(true) {
+            sum += i as Solution + 1;
+        }
+    }
+    sum
+}
+
+/// The index of a divider follows from counting the packets in front of it. Packets the rules can
+/// not tell apart from a divider go in front of it too.
+pub fn part_2(pairs: &ParseOutput) -> Solution {
+    let packets: Vec<&Package> = pairs.iter().flat_map(|(l, r)| [l, r]).collect();
+    let before = |d: &Package| {
+        packets
+            .iter()
+            .filter(|p| right_order(d, p) != Some(true))
+            .count() as Solution
+    };
+    (before(&divider("[[2]]")) + 1) * (before(&divider("[[6]]")) + 2)
+}
@@ -0,0 +1,249 @@
+use crate::animation::{Cell, Frames, Viewport};
+use crate::day::Day;
+use crate::grid::{Grid, Pos};
+use crate::parse::{ParseError, Source};
+use crate::point::Point2;
+use crate::random::Rng;
+use std::cmp::{max, min};
+
+pub mod reference;
+
+type Solution = u32;
+
+type RockLines = Vec<Vec<(usize, usize)>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    Air,
+    Rock,
+    Sand,
+}
+
+/// Sand falls straight down, then down to the left, then down to the right.
+const FALL: [Point2; 3] = [Point2::new(1, 0), Point2::new(1, -1), Point2::new(1, 1)];
+
+pub type ParseOutput = Grid<FieldType>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let mut min_c = (usize::MAX, usize::MAX);
+    let mut max_c = (0, 0);
+
+    let source = Source::new(file);
+    let rock_lines: RockLines = source
+        .lines()
+        .map(|l| {
+            l.split(" -> ")
+                .map(|r| {
+                    let (x_s, y_s) = source.split_once(r, ",")?;
+                    let (y, x) = (source.number(y_s)?, source.number(x_s)?);
+                    min_c = (min(min_c.0, y), min(min_c.1, x));
+                    max_c = (max(max_c.0, y), max(max_c.1, x));
+                    Ok((y, x))
+                })
+                .collect()
+        })
+        .collect::<Result<RockLines, ParseError>>()?;
+    if rock_lines.is_empty() {
+        return Err(source.error(source.end(), "a path of rock"));
+    }
+
+    let mut grid = Grid::new((max_c.0 + 4, max(max_c.1, 500) + 500), FieldType::Air);
+    for rock_line in rock_lines {
+        for m in rock_line[..].windows(2) {
+            match m {
+                &[(y0, x0), (y1, x1)] => {
+                    let (min_y, min_x) = ((min(y0, y1)), (min(x0, x1)));
+                    let (max_y, max_x) = ((max(y0, y1)), (max(x0, x1)));
+                    for y in min_y..max_y + 1 {
+                        for x in min_x..max_x + 1 {
+                            grid[(y, x)] = FieldType::Rock;
+                        }
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    Ok(grid)
+}
+
+/// `size` paths of rock with two to five straight segments, below and around the sand source.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let depth = (10 + 2 * size as i64).min(170);
+    (0..size)
+        .map(|_| {
+            let (mut x, mut y) = (rng.range(470..531), rng.range(5..depth));
+            let mut points = vec![format!("{},{}", x, y)];
+            for i in 0..rng.range(1..5) {
+                let length = rng.range(1..9) * *rng.choose(&[-1, 1]);
+                match i % 2 {
+                    0 => x += length,
+                    _ => y = (y + length).max(1),
+                }
+                points.push(format!("{},{}", x, y));
+            }
+            points.join(" -> ") + "\n"
+        })
+        .collect()
+}
+
+/// Where a corn of sand at `pos` moves next, `None` once it comes to rest.
+fn fall(grid: &Grid<FieldType>, pos: Pos) -> Option<Pos> {
+    FALL.iter()
+        .filter_map(|d| grid.offset(pos, (*d).into()))
+        .find(|p| grid[*p] == FieldType::Air)
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Solution {
+    let mut solution = 0;
+    let mut grid = parse_output.clone();
+    let grid_max_y = grid.height();
+    'outer: loop {
+        let mut sand_corn = (0, 500);
+        solution += 1;
+        loop {
+            let Some(next) = fall(&grid, sand_corn) else {
+                if sand_corn == (0, 500) {
+                    return solution;
+                }
+                grid[sand_corn] = FieldType::Sand;
+                break;
+            };
+            sand_corn = next;
+            if sand_corn.0 + 2 > grid_max_y {
+                break 'outer;
+            }
+        }
+    }
+    solution - 1
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Solution {
+    fill_to_source(parse_output, |_, _| true)
+}
+
+/// Drops sand onto the floor until it blocks the source, calling `on_rest` for every corn that
+/// comes to rest and stopping early once it returns false.
+fn fill_to_source<F: FnMut(&Grid<FieldType>, Pos) -> bool>(
+    parse_output: &ParseOutput,
+    mut on_rest: F,
+) -> Solution {
+    let mut solution = 0;
+    let mut grid = parse_output.clone();
+    let floor = grid.height() - 2;
+    grid.row_mut(floor).fill(FieldType::Rock);
+    'outer: loop {
+        let mut sand_corn = (0, 500);
+        solution += 1;
+        loop {
+            let Some(next) = fall(&grid, sand_corn) else {
+                grid[sand_corn] = FieldType::Sand;
+                if sand_corn == (0, 500) || !on_rest(&grid, sand_corn) {
+                    break 'outer;
+                }
+                break;
+            };
+            sand_corn = next;
+        }
+    }
+    solution
+}
+
+pub fn animate(parse_output: &ParseOutput, frames: &mut dyn Frames) {
+    let (height, width) = parse_output.size();
+    let floor = (height - 2) as i32;
+    let (x_min, x_max) = (
+        (500 - floor - 1).max(0),
+        (500 + floor + 1).min(width as i32 - 1),
+    );
+    let bounds = Viewport::new(0, x_min, floor, x_max);
+    let mut corns = 0;
+    fill_to_source(parse_output, |grid, (y, x)| {
+        corns += 1;
+        let caption = format!("{} corns of sand", corns);
+        frames.frame(&bounds, (y as i32, x as i32), &caption, &mut |cy, cx| {
+            if (cy as usize, cx as usize) == (y, x) {
+                return Cell::Actor;
+            }
+            match grid[(cy as usize, cx as usize)] {
+                FieldType::Air => Cell::Empty,
+                FieldType::Rock => Cell::Wall,
+                FieldType::Sand => Cell::Marker('o'),
+            }
+        })
+    });
+}
+
+pub struct Day14;
+
+impl Day for Day14 {
+    const DAY: u8 = 14;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+    const ANIMATED: bool = true;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Solution {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Solution {
+        part_2(input)
+    }
+
+    fn animate(input: &ParseOutput, _params: &(), frames: &mut dyn Frames) {
+        animate(input, frames)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Solution> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 24);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 93);
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        assert_eq!(parse("").unwrap_err().expected, "a path of rock");
+        let parse_output = parse("0,2 -> 1,2").unwrap();
+        assert_eq!((part_1(&parse_output), part_2(&parse_output)), (0, 16));
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day14, 0..10, 10);
+    }
+}
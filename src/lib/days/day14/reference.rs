@@ -0,0 +1,41 @@
+use super::{FieldType, ParseOutput, Solution};
+use std::collections::HashSet;
+
+/// Drops sand from (0, 500) one corn at a time until a corn falls below all rock or the source is
+/// covered. With a `floor` there is endless rock two rows below the lowest one.
+fn rest(grid: &ParseOutput, floor: bool) -> Solution {
+    let mut blocked: HashSet<(usize, usize)> = grid
+        .iter()
+        .filter(|(_, field)| **field == FieldType::Rock)
+        .map(|(pos, _)| pos)
+        .collect();
+    let lowest = blocked.iter().map(|(y, _)| *y).max().unwrap_or(0);
+    let floor = floor.then_some(lowest + 2);
+    let mut corns = 0;
+    while !blocked.contains(&(0, 500)) {
+        let (mut y, mut x) = (0, 500);
+        loop {
+            if floor.is_none() && y > lowest {
+                return corns;
+            }
+            let below = [(y + 1, x), (y + 1, x - 1), (y + 1, x + 1)]
+                .into_iter()
+                .find(|p| !blocked.contains(p) && Some(p.0) != floor);
+            match below {
+                Some(next) => (y, x) = next,
+                None => break,
+            }
+        }
+        blocked.insert((y, x));
+        corns += 1;
+    }
+    corns
+}
+
+pub fn part_1(grid: &ParseOutput) -> Solution {
+    rest(grid, false)
+}
+
+pub fn part_2(grid: &ParseOutput) -> Solution {
+    rest(grid, true)
+}
@@ -0,0 +1,249 @@
+use crate::combinator::{lines, literal, number, parse_all, Parser};
+use crate::day::{Day, TEST};
+use crate::parse::{ParseError, Source};
+use crate::pool;
+use crate::random::Rng;
+
+pub mod reference;
+
+type Solution = i64;
+
+const MAX_SEARCH_P2: Solution = 4_000_000;
+const Y_P1: Solution = 2_000_000;
+const ROWS_PER_JOB: usize = 10_000;
+
+#[derive(Debug, Clone)]
+pub struct Params {
+    pub y_p1: Solution,
+    pub max_search_p2: Solution,
+}
+
+impl Default for Params {
+    fn default() -> Params {
+        Params {
+            y_p1: Y_P1,
+            max_search_p2: MAX_SEARCH_P2,
+        }
+    }
+}
+
+pub type ParseOutput = Vec<(Solution, Solution, Solution, Solution, Solution)>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+fn position<'a>() -> impl Parser<'a, (Solution, Solution)> {
+    literal("x=")
+        .ignore_then(number())
+        .then_ignore(literal(","))
+        .then(literal("y=").ignore_then(number()))
+}
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let sensor = literal("Sensor at")
+        .ignore_then(position())
+        .then_ignore(literal(":"))
+        .then_ignore(literal("closest beacon is at"))
+        .then(position())
+        .map(|((sx, sy), (bx, by))| (sy, sx, by, bx, manhattan_distance((sy, sx), (by, bx))));
+    parse_all(&Source::new(file), lines(sensor))
+}
+
+/// `size` sensors with small ranges that stay clear of one random gap, and four with huge ranges
+/// that cover everything else. Turned by 45 degrees the ranges are squares, and the big ones sit
+/// on the four sides of the gap with their edges just next to it.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let max = MAX_SEARCH_P2;
+    let gap = (rng.range(0..max + 1), rng.range(0..max + 1));
+    let (u, v) = (gap.0 + gap.1, gap.0 - gap.1);
+    // Odd, so the centers land on whole coordinates.
+    let range = 4 * max + 1;
+    let mut sensors: Vec<((Solution, Solution), Solution)> = [
+        (u - 1 - range, v),
+        (u + 1 + range, v),
+        (u, v - 1 - range),
+        (u, v + 1 + range),
+    ]
+    .map(|(u, v)| (((u + v) / 2, (u - v) / 2), range))
+    .to_vec();
+    while sensors.len() < size + 4 {
+        let sensor = (rng.range(0..max + 1), rng.range(0..max + 1));
+        let distance = manhattan_distance(sensor, gap);
+        if distance > 1 {
+            sensors.push((sensor, rng.range(1..distance)));
+        }
+    }
+    rng.shuffle(&mut sensors);
+
+    sensors
+        .into_iter()
+        .map(|((x, y), range)| {
+            let dx = rng.range(-range..range + 1);
+            let dy = (range - dx.abs()) * rng.choose(&[-1, 1]);
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                x,
+                y,
+                x + dx,
+                y + dy
+            )
+        })
+        .collect()
+}
+
+pub fn part_1(beacons_and_signals: &ParseOutput, y: Solution) -> Solution {
+    let mut ranges = Vec::new();
+    clear_and_push_sorted_ranges(y, beacons_and_signals, &mut ranges);
+
+    let mut beacons: Vec<Solution> = beacons_and_signals
+        .iter()
+        .filter(|r| r.2 == y)
+        .map(|r| r.2)
+        .collect();
+    beacons.sort();
+    beacons.dedup();
+
+    count_impossible(&mut ranges) - beacons.len() as Solution
+}
+
+pub fn part_2(parse_output: &ParseOutput, max: Solution) -> Solution {
+    let beacons_and_signals = parse_output;
+    let (x, y) = pool::find_first(0..max as usize, ROWS_PER_JOB, |y| {
+        let mut ranges = Vec::new();
+        clear_and_push_sorted_ranges(y as Solution, beacons_and_signals, &mut ranges);
+        get_free_x(&mut ranges).map(|free_x| (free_x, y as Solution))
+    })
+    .unwrap_or((0, max - 1));
+
+    (MAX_SEARCH_P2 * x) + y
+}
+
+fn count_impossible(ranges: &mut [(Solution, Solution)]) -> Solution {
+    let (Some(r_min), Some(r_max)) = (
+        ranges.iter().map(|r| r.0).min(),
+        ranges.iter().map(|r| r.1).max(),
+    ) else {
+        return 0;
+    };
+    let mut solution = r_max - r_min;
+    if get_free_x(ranges).is_some() {
+        solution -= 1;
+    }
+    solution
+}
+
+fn get_free_x(ranges: &mut [(Solution, Solution)]) -> Option<Solution> {
+    let mut p = ranges.first()?.1;
+    for r in ranges.iter().skip(1) {
+        if r.0 <= p {
+            p = r.1.max(p);
+        } else {
+            return Some(p);
+        }
+    }
+
+    None
+}
+
+fn clear_and_push_sorted_ranges(
+    y: Solution,
+    beacons_and_signals: &ParseOutput,
+    ranges: &mut Vec<(Solution, Solution)>,
+) {
+    ranges.clear();
+    for beacon_and_signal in beacons_and_signals {
+        let (sy, sx, _, _, s_b_distance) = beacon_and_signal;
+        let row_signal = s_b_distance - (sy.abs_diff(y) as Solution);
+        if row_signal < 0 {
+            continue;
+        }
+        let signal_range = (sx - row_signal, sx + row_signal + 1);
+        ranges.push(signal_range);
+    }
+    ranges.sort();
+}
+
+pub fn manhattan_distance(p1: (Solution, Solution), p2: (Solution, Solution)) -> Solution {
+    (p1.0.abs_diff(p2.0) + p1.1.abs_diff(p2.1)) as Solution
+}
+
+pub struct Day15;
+
+impl Day for Day15 {
+    const DAY: u8 = 15;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = Params;
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, params: &Params) -> Solution {
+        part_1(input, params.y_p1)
+    }
+
+    fn part_2(input: &ParseOutput, params: &Params) -> Solution {
+        part_2(input, params.max_search_p2)
+    }
+
+    fn params(input_name: &str) -> Params {
+        if input_name == TEST {
+            Params {
+                y_p1: 10,
+                max_search_p2: 20,
+            }
+        } else {
+            Params::default()
+        }
+    }
+
+    fn reference_1(input: &ParseOutput, params: &Params) -> Option<Solution> {
+        Some(reference::part_1(input, params.y_p1))
+    }
+
+    fn reference_2(input: &ParseOutput, params: &Params) -> Option<Solution> {
+        Some(reference::part_2(input, params.max_search_p2))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const MAX_SEARCH_P2_TEST: Solution = 20;
+    const Y_P1_TEST: Solution = 10;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output, Y_P1_TEST), 26);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output, MAX_SEARCH_P2_TEST), 56000011);
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        let error = parse("Sensor at x=2, y=18: closest beacon is at x=-2").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 47));
+        assert_eq!(parse("").unwrap_err().expected, "'Sensor at'");
+        let single = parse("Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n").unwrap();
+        assert_eq!(part_1(&single, Y_P1_TEST), 0);
+        assert_eq!(part_1(&single, 18), 15);
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day15, 0..2, 5);
+    }
+}
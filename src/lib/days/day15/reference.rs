@@ -0,0 +1,52 @@
+use super::{ParseOutput, Solution, MAX_SEARCH_P2};
+
+/// The columns of row `y` a sensor covers, both ends included.
+fn covered(sensors: &ParseOutput, y: Solution) -> Vec<(Solution, Solution)> {
+    sensors
+        .iter()
+        .filter_map(|(sy, sx, _, _, distance)| {
+            let reach = distance - (sy - y).abs();
+            (reach >= 0).then_some((sx - reach, sx + reach))
+        })
+        .collect()
+}
+
+/// Merges the covered columns of the row into separate runs and counts them, leaving out the
+/// beacons that are on it.
+pub fn part_1(sensors: &ParseOutput, y: Solution) -> Solution {
+    let mut columns = covered(sensors, y);
+    columns.sort();
+    let mut runs: Vec<(Solution, Solution)> = Vec::new();
+    for (start, end) in columns {
+        match runs.last_mut() {
+            Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+            _ => runs.push((start, end)),
+        }
+    }
+    let mut beacons: Vec<Solution> = sensors.iter().filter(|s| s.2 == y).map(|s| s.3).collect();
+    beacons.sort();
+    beacons.dedup();
+    runs.iter()
+        .map(|(start, end)| end - start + 1)
+        .sum::<Solution>()
+        - beacons.len() as Solution
+}
+
+/// Walks every row of the search area from the left, jumping to the end of the range of the
+/// first sensor covering the current position, until a position no sensor covers turns up.
+pub fn part_2(sensors: &ParseOutput, max: Solution) -> Solution {
+    for y in 0..=max {
+        let columns = covered(sensors, y);
+        let mut x = 0;
+        while x <= max {
+            match columns
+                .iter()
+                .find(|(start, end)| (*start..=*end).contains(&x))
+            {
+                Some((_, end)) => x = end + 1,
+                None => return MAX_SEARCH_P2 * x + y,
+            }
+        }
+    }
+    0
+}
@@ -0,0 +1,30 @@
+use super::Sol;
+use crate::search::Graph;
+use std::collections::HashMap;
+
+type Cost = Sol;
+type NodeIndex = usize;
+pub type ValveIndex = usize;
+pub type Rate = Sol;
+
+pub type Routes = HashMap<ValveIndex, (NodeIndex, Cost, Rate)>;
+pub type RoutingPath = Vec<ValveIndex>;
+
+#[derive(Debug, Clone)]
+pub struct CaveNode {
+    pub routes: Routes,
+    pub paths: Vec<NodeIndex>,
+    pub rate: Rate,
+}
+
+/// The cave as a graph where every tunnel takes a minute.
+pub struct Tunnels<'a>(pub &'a [CaveNode]);
+
+impl Graph for Tunnels<'_> {
+    type Node = NodeIndex;
+    type Cost = Cost;
+
+    fn neighbours(&self, node: &NodeIndex) -> Vec<(NodeIndex, Cost)> {
+        self.0[*node].paths.iter().map(|next| (*next, 1)).collect()
+    }
+}
@@ -0,0 +1,399 @@
+extern crate core;
+
+use crate::combinator::{lines, literal, number, parse_all, word, Parser};
+use crate::day::Day;
+use crate::log::Progress;
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+use crate::search;
+use cave::{CaveNode, Tunnels, ValveIndex};
+use std::collections::HashMap;
+
+pub mod cave;
+pub mod reference;
+
+type Sol = i32;
+const COST_OF_OPENING_VALVE: Sol = 1;
+
+pub type ParseOutput = (
+    Vec<CaveNode>,
+    Vec<ValveIndex>,
+    HashMap<usize, String>,
+    usize,
+);
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let mut lavel_to_index_map = HashMap::new();
+    let mut t_map = HashMap::new();
+    let mut index_to_label_map = HashMap::new();
+    let mut valves: Vec<usize> = Vec::new();
+    let mut start_i = None;
+    let source = Source::new(file);
+    let valve = literal("Valve")
+        .ignore_then(word())
+        .then_ignore(literal("has flow rate="))
+        .then(number::<Sol>())
+        .then_ignore(literal(";"))
+        .then_ignore(literal("tunnels lead to valves").or(literal("tunnel leads to valve")))
+        .then(word().separated_by(literal(",")));
+    let mut cave_system: Vec<CaveNode> = parse_all(&source, lines(valve))?
+        .into_iter()
+        .enumerate()
+        .map(|(i, ((cave_label, rate), t))| {
+            if cave_label == "AA" {
+                start_i = Some(i);
+            }
+            lavel_to_index_map.insert(cave_label, i);
+            index_to_label_map.insert(i, cave_label.to_string());
+            t_map.insert(i, t);
+            if rate > 0 {
+                valves.push(i);
+            }
+
+            CaveNode {
+                rate,
+                paths: Vec::new(),
+                routes: HashMap::new(),
+            }
+        })
+        .collect();
+    let start_i = start_i.ok_or_else(|| source.error(source.end(), "a valve AA"))?;
+
+    for (i, cave) in cave_system.iter_mut().enumerate() {
+        if let Some(t) = t_map.get(&i) {
+            cave.paths = t
+                .iter()
+                .map(|ti| {
+                    lavel_to_index_map
+                        .get(ti)
+                        .copied()
+                        .ok_or_else(|| source.error(ti, "a known valve"))
+                })
+                .collect::<Result<Vec<usize>, ParseError>>()?
+        }
+    }
+
+    for vi in &valves {
+        let rate = cave_system[*vi].rate;
+        let search = search::bfs(&Tunnels(&cave_system), [*vi], |_| false);
+        let routes: Vec<(usize, usize, Sol)> = search
+            .reached()
+            .filter_map(|(current_i, cost)| {
+                search
+                    .parent(current_i)
+                    .map(|last_i| (*current_i, *last_i, cost))
+            })
+            .collect();
+        for (current_i, last_i, cost) in routes {
+            cave_system[current_i]
+                .routes
+                .insert(*vi, (last_i, cost, rate));
+        }
+    }
+
+    Ok((cave_system, valves, index_to_label_map, start_i))
+}
+
+/// `size` valves, at least two, connected through a random tree with a few extra tunnels.
+/// About a quarter of them have a flow rate, at most fifteen like the puzzle inputs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 26 * 26);
+    let mut labels: Vec<String> = (1..26 * 26)
+        .map(|i| {
+            format!(
+                "{}{}",
+                (b'A' + (i / 26) as u8) as char,
+                (b'A' + (i % 26) as u8) as char
+            )
+        })
+        .collect();
+    rng.shuffle(&mut labels);
+    labels.insert(0, "AA".into());
+    labels.truncate(count);
+
+    let mut tunnels = vec![Vec::new(); count];
+    let mut connect = |a: usize, b: usize| {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    };
+    for i in 1..count {
+        connect(i, rng.below(i));
+    }
+    for _ in 0..count / 4 {
+        connect(rng.below(count), rng.below(count));
+    }
+
+    let mut flowing: Vec<usize> = (1..count).collect();
+    rng.shuffle(&mut flowing);
+    flowing.truncate((count / 4).clamp(1, 15));
+
+    (0..count)
+        .map(|i| {
+            let rate = match flowing.contains(&i) {
+                true => rng.range(3..26),
+                false => 0,
+            };
+            let to: Vec<&str> = tunnels[i].iter().map(|t| labels[*t].as_str()).collect();
+            let lead = match to.len() {
+                1 => "tunnel leads to valve",
+                _ => "tunnels lead to valves",
+            };
+            format!(
+                "Valve {} has flow rate={}; {} {}\n",
+                labels[i],
+                rate,
+                lead,
+                to.join(", ")
+            )
+        })
+        .collect()
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Sol {
+    let (cave_system, valves, index_to_label_map, start_i) = &parse_output;
+    let mut solution = 0;
+    permutate(
+        valves.iter().map(|v_i| (*v_i, 0)).collect(),
+        vec![*start_i],
+        cave_system,
+        0,
+        30,
+        *start_i,
+        &mut |valve_idx| {
+            let flow_rate_at = calculate_flow_rate_at(cave_system, valve_idx, index_to_label_map);
+
+            solution = solution.max(calc_total_flow(&flow_rate_at, 30));
+        },
+    );
+
+    solution
+}
+
+fn calc_total_flow(flow_rate_at: &Vec<(Sol, Sol)>, minutes: Sol) -> i32 {
+    let mut current_flow = 0;
+    let mut last_time = 0;
+    let mut sol = 0;
+    for (now, rate) in flow_rate_at {
+        let delta = now - last_time;
+
+        sol += delta * current_flow;
+        current_flow += rate;
+        last_time = *now;
+    }
+    sol += (minutes - last_time) * current_flow;
+    sol
+}
+
+fn calculate_flow_rate_at(
+    cave_system: &[CaveNode],
+    valve_idx: &[usize],
+    _index_to_label_map: &HashMap<usize, String>,
+) -> Vec<(Sol, Sol)> {
+    let mut minute = 0;
+    let mut flow_rate_added_at: Vec<(Sol, Sol)> = Vec::new();
+    for step in valve_idx.windows(2) {
+        let Some((_, cost, rate)) = cave_system[step[0]].routes.get(&step[1]) else {
+            break;
+        };
+        minute += cost + COST_OF_OPENING_VALVE;
+        flow_rate_added_at.push((minute, *rate));
+    }
+
+    flow_rate_added_at
+}
+
+/// Calls `cb` with every order of opening valves that fits in `minutes`, starting with the
+/// valves opened so far in `nice`, and with each of its beginnings.
+pub fn permutate<F: FnMut(&Vec<usize>)>(
+    idx: Vec<(usize, Sol)>,
+    nice: Vec<usize>,
+    cave_system: &[CaveNode],
+    current_cost: Sol,
+    minutes: Sol,
+    current_i: usize,
+    cb: &mut F,
+) {
+    cb(&nice);
+    let new_idx: Vec<(usize, Sol)> = idx
+        .iter()
+        .filter_map(|(v_i, _)| {
+            if let Some((_, cost_to_next_node, _)) = cave_system[current_i].routes.get(v_i) {
+                let new_cost = current_cost + cost_to_next_node + COST_OF_OPENING_VALVE;
+                if new_cost <= minutes {
+                    return Some((*v_i, new_cost));
+                }
+            }
+            None
+        })
+        .collect();
+
+    for (v_i, new_cost) in new_idx.iter() {
+        permutate(
+            new_idx.iter().filter(|(v, _)| v != v_i).copied().collect(),
+            nice.iter().chain(&[*v_i]).cloned().collect(),
+            cave_system,
+            *new_cost,
+            minutes,
+            *v_i,
+            cb,
+        );
+    }
+}
+
+/// You and the elephant open different valves, so the answer is the best pair of disjoint sets of
+/// valves, each scored by the most pressure one walker releases opening just them. Bit `i` of a
+/// set stands for `valves[i]`.
+pub fn part_2(parse_output: &ParseOutput) -> Sol {
+    let (cave_system, valves, index_to_label_map, start_i) = &parse_output;
+    let mut progress = Progress::new("day 16 orders");
+    let mut best = HashMap::<u64, Sol>::new();
+    permutate(
+        valves.iter().map(|v_i| (*v_i, 0)).collect(),
+        vec![*start_i],
+        cave_system,
+        0,
+        26,
+        *start_i,
+        &mut |valve_idx| {
+            progress.tick();
+            let flow_rate_at = calculate_flow_rate_at(cave_system, valve_idx, index_to_label_map);
+            let flow = calc_total_flow(&flow_rate_at, 26);
+            let opened = valve_idx[1..]
+                .iter()
+                .filter_map(|v| valves.iter().position(|valve| valve == v))
+                .fold(0, |set, bit| set | 1 << bit);
+            let most = best.entry(opened).or_insert(flow);
+            *most = flow.max(*most);
+        },
+    );
+    progress.finish();
+
+    let mut solution = 0;
+    for (you, you_flow) in &best {
+        for (elephant, elephant_flow) in &best {
+            if you & elephant == 0 && you_flow + elephant_flow > solution {
+                solution = you_flow + elephant_flow;
+                crate::trace!("you open {:b}, the elephant {:b}", you, elephant);
+            }
+        }
+    }
+    solution
+}
+
+pub fn permutation_3<F: FnMut(&Vec<usize>, &Vec<usize>)>(
+    left: Vec<usize>,
+    you: Vec<usize>,
+    elephant: Vec<usize>,
+    cave_system: &[CaveNode],
+    current_you_cost: Sol,
+    current_elephant_cost: Sol,
+    cb: &mut F,
+) {
+    let mut both_end = true;
+    for v_i in &left {
+        let new_left: Vec<usize> = left
+            .iter()
+            .filter_map(|l| if l != v_i { Some(*l) } else { None })
+            .collect();
+
+        let next_you_cost = current_you_cost + route_cost(cave_system, you[you.len() - 1], v_i);
+        if next_you_cost <= 26 {
+            both_end = false;
+            permutation_3(
+                new_left.clone(),
+                you.iter().chain(&[*v_i]).copied().collect(),
+                elephant.clone(),
+                cave_system,
+                next_you_cost,
+                current_elephant_cost,
+                cb,
+            );
+        }
+
+        let next_elephant_cost =
+            current_elephant_cost + route_cost(cave_system, elephant[elephant.len() - 1], v_i);
+        if next_elephant_cost <= 26 {
+            both_end = false;
+            permutation_3(
+                new_left,
+                you.clone(),
+                elephant.iter().chain(&[*v_i]).copied().collect(),
+                cave_system,
+                current_you_cost,
+                next_elephant_cost,
+                cb,
+            );
+        }
+    }
+
+    if both_end {
+        cb(&you, &elephant);
+    }
+}
+
+pub fn route_cost(cave_system: &[CaveNode], c_i: usize, v_i: &usize) -> Sol {
+    cave_system[c_i].routes.get(v_i).unwrap().1 + COST_OF_OPENING_VALVE
+}
+
+pub struct Day16;
+
+impl Day for Day16 {
+    const DAY: u8 = 16;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Sol;
+    type Answer2 = Sol;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Sol {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Sol {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Sol> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Sol> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 1651);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 1707);
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day16, 0..10, 10);
+    }
+}
@@ -0,0 +1,53 @@
+use super::{ParseOutput, Sol};
+use std::collections::HashMap;
+
+/// The most pressure one walker can release in `minutes` for every set of valves it ends up
+/// opening, found by trying every move minute by minute. Bit `i` of a set stands for the `i`th
+/// valve with a flow rate.
+fn released(parse_output: &ParseOutput, minutes: Sol) -> HashMap<u32, Sol> {
+    let (caves, valves, _, start) = parse_output;
+    let mut states = HashMap::from([((*start, 0), 0)]);
+    for minute in 1..=minutes {
+        let mut next = HashMap::new();
+        let mut push = |state: (usize, u32), pressure: Sol| {
+            let best = next.entry(state).or_insert(pressure);
+            *best = pressure.max(*best);
+        };
+        for ((cave, open), pressure) in states {
+            if let Some(bit) = valves.iter().position(|v| *v == cave) {
+                if open & 1 << bit == 0 {
+                    let flow = caves[cave].rate * (minutes - minute);
+                    push((cave, open | 1 << bit), pressure + flow);
+                }
+            }
+            for tunnel in &caves[cave].paths {
+                push((*tunnel, open), pressure);
+            }
+        }
+        states = next;
+    }
+    let mut best = HashMap::new();
+    for ((_, open), pressure) in states {
+        let b = best.entry(open).or_insert(pressure);
+        *b = pressure.max(*b);
+    }
+    best
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Sol {
+    released(parse_output, 30).into_values().max().unwrap_or(0)
+}
+
+/// You and the elephant open different valves, so the best pair of disjoint sets wins.
+pub fn part_2(parse_output: &ParseOutput) -> Sol {
+    let best = released(parse_output, 26);
+    let mut most = 0;
+    for (you, y) in &best {
+        for (elephant, e) in &best {
+            if you & elephant == 0 {
+                most = most.max(y + e);
+            }
+        }
+    }
+    most
+}
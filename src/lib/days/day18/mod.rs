@@ -0,0 +1,166 @@
+use crate::day::Day;
+use crate::parse::{ParseError, Source};
+use crate::point::Point3;
+use crate::random::Rng;
+use std::collections::HashSet;
+
+pub mod reference;
+
+type Sol = i32;
+
+pub type Cube = Point3;
+pub type ParseOutput = HashSet<Cube>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    source
+        .lines()
+        .map(|l| match l.split(',').collect::<Vec<&str>>()[..] {
+            [z, y, x] => Ok(Point3::new(
+                source.number(z)?,
+                source.number(y)?,
+                source.number(x)?,
+            )),
+            _ => Err(source.error(l, "three comma separated coordinates")),
+        })
+        .collect()
+}
+
+/// `size` different cubes packed into a box about two and a half times their volume, which
+/// leaves pockets of air inside.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut side = 1;
+    while side * side * side * 2 < size * 5 {
+        side += 1;
+    }
+    let mut cubes = HashSet::new();
+    let mut file = String::new();
+    while cubes.len() < size {
+        let cube = [0; 3].map(|_| 1 + rng.below(side));
+        if cubes.insert(cube) {
+            file.push_str(&format!("{},{},{}\n", cube[0], cube[1], cube[2]));
+        }
+    }
+    file
+}
+
+pub fn part_1(cubes: &ParseOutput) -> Sol {
+    get_surface_area(cubes)
+}
+
+fn get_surface_area(cubes: &ParseOutput) -> Sol {
+    cubes
+        .iter()
+        .flat_map(|cube| cube.neighbours_6())
+        .filter(|side| !cubes.contains(side))
+        .count() as Sol
+}
+
+pub fn part_2(cubes: &ParseOutput) -> Sol {
+    let min_cube = cubes
+        .iter()
+        .fold(Point3::new(Sol::MAX, Sol::MAX, Sol::MAX), |a, c| a.min(*c));
+    let max_cube = cubes
+        .iter()
+        .fold(Point3::new(Sol::MIN, Sol::MIN, Sol::MIN), |a, c| a.max(*c));
+
+    let air = get_air(cubes, &min_cube, &max_cube);
+    let mut holes = HashSet::new();
+    for z in min_cube.z..=max_cube.z {
+        for y in min_cube.y..=max_cube.y {
+            for x in min_cube.x..=max_cube.x {
+                let cube = Point3::new(z, y, x);
+                if !air.contains(&cube) && !cubes.contains(&cube) {
+                    holes.insert(cube);
+                }
+            }
+        }
+    }
+
+    let surface_all = get_surface_area(cubes);
+    let surface_holes = get_surface_area(&holes);
+
+    surface_all - surface_holes
+}
+
+fn get_air(cubes: &ParseOutput, min_cube: &Cube, max_cube: &Cube) -> HashSet<Cube> {
+    let margin = Point3::new(1, 1, 1);
+    let (low, high) = (*min_cube - margin, *max_cube + margin);
+    let mut air_cubes = HashSet::new();
+    let current_path = &mut vec![low];
+    while let Some(cube) = current_path.pop() {
+        if cube.min(low) != low || cube.max(high) != high {
+            continue;
+        }
+        if cubes.contains(&cube) {
+            continue;
+        }
+        if !air_cubes.insert(cube) {
+            continue;
+        }
+
+        current_path.extend(cube.neighbours_6());
+    }
+    air_cubes
+}
+
+pub struct Day18;
+
+impl Day for Day18 {
+    const DAY: u8 = 18;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Sol;
+    type Answer2 = Sol;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Sol {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Sol {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Sol> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Sol> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 64);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 58);
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day18, 0..20, 30);
+    }
+}
@@ -0,0 +1,50 @@
+use super::{Cube, ParseOutput, Sol};
+use std::collections::HashSet;
+
+/// Every side of every cube that does not touch another cube.
+fn open_sides(cubes: &ParseOutput) -> Vec<Cube> {
+    let mut sides = Vec::new();
+    for cube in cubes {
+        for side in cube.neighbours_6() {
+            if !cubes.contains(&side) {
+                sides.push(side);
+            }
+        }
+    }
+    sides
+}
+
+/// Whether steam can get from `start` to outside the box around all cubes.
+fn escapes(cubes: &ParseOutput, start: Cube, min: Cube, max: Cube) -> bool {
+    let mut seen = HashSet::from([start]);
+    let mut todo = vec![start];
+    while let Some(cube) = todo.pop() {
+        if cube.min(min) != min || cube.max(max) != max {
+            return true;
+        }
+        for next in cube.neighbours_6() {
+            if !cubes.contains(&next) && seen.insert(next) {
+                todo.push(next);
+            }
+        }
+    }
+    false
+}
+
+pub fn part_1(cubes: &ParseOutput) -> Sol {
+    open_sides(cubes).len() as Sol
+}
+
+/// Searches the way out separately for every open side.
+pub fn part_2(cubes: &ParseOutput) -> Sol {
+    let Some(first) = cubes.iter().next() else {
+        return 0;
+    };
+    let (min, max) = cubes
+        .iter()
+        .fold((*first, *first), |(min, max), c| (min.min(*c), max.max(*c)));
+    open_sides(cubes)
+        .into_iter()
+        .filter(|side| escapes(cubes, *side, min, max))
+        .count() as Sol
+}
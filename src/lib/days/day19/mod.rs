@@ -0,0 +1,352 @@
+use crate::combinator::{lines, literal, number, parse_all, word, Parser};
+use crate::day::Day;
+use crate::log::Progress;
+use crate::parse::{ParseError, Source};
+use crate::pool;
+use crate::random::Rng;
+use std::collections::HashSet;
+
+pub mod reference;
+
+type Sol = i32;
+
+#[derive(Debug, Clone)]
+pub struct Blueprint {
+    ore_rob_ore_cost: Sol,
+    clay_rob_ore_cost: Sol,
+    obs_rob_ore_cost: Sol,
+    obs_rob_clay_cost: Sol,
+    geo_rob_ore_cost: Sol,
+    geo_rob_obs_cost: Sol,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct SimulationState {
+    minute: Sol,
+    ore_robs: Sol,
+    clay_robs: Sol,
+    obs_robs: Sol,
+    geo_robs: Sol,
+    ore: Sol,
+    clay: Sol,
+    obs: Sol,
+    geo: Sol,
+    /// The ore, clay and obsidian robots the factory could have built instead of waiting.
+    skipped: [bool; 3],
+}
+
+impl Default for SimulationState {
+    fn default() -> SimulationState {
+        SimulationState {
+            minute: 0,
+            ore_robs: 1,
+            clay_robs: 0,
+            obs_robs: 0,
+            geo_robs: 0,
+            ore: 0,
+            clay: 0,
+            obs: 0,
+            geo: 0,
+            skipped: [false; 3],
+        }
+    }
+}
+
+impl SimulationState {
+    pub fn build_ore_rob(&self, blueprint: &Blueprint) -> Option<Self> {
+        if self.ore >= blueprint.ore_rob_ore_cost {
+            let mut n = self.clone();
+            n.ore -= blueprint.ore_rob_ore_cost;
+            n.advance_factory();
+            n.ore_robs += 1;
+            return Some(n);
+        }
+        None
+    }
+
+    pub fn build_clay_rob(&self, blueprint: &Blueprint) -> Option<Self> {
+        if self.ore >= blueprint.clay_rob_ore_cost {
+            let mut n = self.clone();
+            n.ore -= blueprint.clay_rob_ore_cost;
+            n.advance_factory();
+            n.clay_robs += 1;
+            return Some(n);
+        }
+        None
+    }
+
+    pub fn build_obs_rob(&self, blueprint: &Blueprint) -> Option<Self> {
+        if self.ore >= blueprint.obs_rob_ore_cost && self.clay >= blueprint.obs_rob_clay_cost {
+            let mut n = self.clone();
+            n.ore -= blueprint.obs_rob_ore_cost;
+            n.clay -= blueprint.obs_rob_clay_cost;
+            n.advance_factory();
+            n.obs_robs += 1;
+            return Some(n);
+        }
+        None
+    }
+
+    pub fn build_geo_rob(&self, blueprint: &Blueprint) -> Option<Self> {
+        if self.ore >= blueprint.geo_rob_ore_cost && self.obs >= blueprint.geo_rob_obs_cost {
+            let mut n = self.clone();
+            n.ore -= blueprint.geo_rob_ore_cost;
+            n.obs -= blueprint.geo_rob_obs_cost;
+            n.advance_factory();
+            n.geo_robs += 1;
+            return Some(n);
+        }
+        None
+    }
+
+    pub fn advance_factory(&mut self) {
+        self.ore += self.ore_robs;
+        self.clay += self.clay_robs;
+        self.obs += self.obs_robs;
+        self.geo += self.geo_robs;
+    }
+}
+
+pub type ParseOutput = Vec<Blueprint>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+type Recipe<'a> = (&'a str, Vec<(Sol, &'a str)>);
+
+const ROBOTS: [&str; 4] = ["ore", "clay", "obsidian", "geode"];
+
+/// "Each ore robot costs 4 ore." The costs of one robot are joined with "and".
+fn recipe<'a>() -> impl Parser<'a, Recipe<'a>> {
+    let resource = || {
+        word().try_map(|w| match ROBOTS.contains(&w) {
+            true => Ok(w),
+            false => Err("'ore', 'clay', 'obsidian' or 'geode'".to_string()),
+        })
+    };
+    literal("Each")
+        .ignore_then(resource())
+        .then_ignore(literal("robot"))
+        .then_ignore(literal("costs"))
+        .then(number().then(resource()).separated_by(literal("and")))
+        .then_ignore(literal("."))
+}
+
+fn blueprint<'a>() -> impl Parser<'a, Blueprint> {
+    literal("Blueprint")
+        .ignore_then(number::<Sol>())
+        .ignore_then(literal(":"))
+        .ignore_then(recipe().many())
+        .try_map(|recipes| {
+            if let Some(missing) = ROBOTS
+                .iter()
+                .find(|r| !recipes.iter().any(|(robot, _)| robot == *r))
+            {
+                return Err(format!("a recipe for the {} robot", missing));
+            }
+            let cost = |robot: &str, resource: &str| {
+                recipes
+                    .iter()
+                    .filter(|(r, _)| *r == robot)
+                    .flat_map(|(_, costs)| costs)
+                    .filter(|(_, r)| *r == resource)
+                    .map(|(n, _)| n)
+                    .sum()
+            };
+            Ok(Blueprint {
+                ore_rob_ore_cost: cost("ore", "ore"),
+                clay_rob_ore_cost: cost("clay", "ore"),
+                obs_rob_ore_cost: cost("obsidian", "ore"),
+                obs_rob_clay_cost: cost("obsidian", "clay"),
+                geo_rob_ore_cost: cost("geode", "ore"),
+                geo_rob_obs_cost: cost("geode", "obsidian"),
+            })
+        })
+}
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    parse_all(&Source::new(file), lines(blueprint()))
+}
+
+/// `size` blueprints with costs in the ranges of the puzzle inputs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (1..=size)
+        .map(|i| {
+            format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                i,
+                rng.range(2..5),
+                rng.range(2..5),
+                rng.range(2..5),
+                rng.range(5..21),
+                rng.range(2..5),
+                rng.range(5..21)
+            )
+        })
+        .collect()
+}
+
+pub fn part_1(blueprints: &ParseOutput) -> Sol {
+    pool::map(blueprints, |blueprint| get_max_geo(blueprint, 24))
+        .iter()
+        .enumerate()
+        .map(|(i, max_geo)| (i as Sol + 1) * max_geo)
+        .sum()
+}
+
+pub fn part_2(blueprints: &ParseOutput) -> Sol {
+    pool::map(&blueprints[..blueprints.len().min(3)], |blueprint| {
+        get_max_geo(blueprint, 32)
+    })
+    .iter()
+    .product()
+}
+
+fn get_max_geo(blueprint: &Blueprint, max_minutes: Sol) -> Sol {
+    let mut visited_states = HashSet::new();
+    let mut possible_simulation_states: Vec<SimulationState> = vec![SimulationState::default()];
+    let mut max_geos: Sol = 0;
+    let max_ore_robs = blueprint
+        .ore_rob_ore_cost
+        .max(blueprint.clay_rob_ore_cost)
+        .max(blueprint.obs_rob_ore_cost)
+        .max(blueprint.geo_rob_ore_cost);
+    let max_clay_robs = blueprint.obs_rob_clay_cost;
+    let max_obs_robs = blueprint.geo_rob_obs_cost;
+    let mut progress = Progress::new("day 19 blueprint");
+    while let Some(mut state) = possible_simulation_states.pop() {
+        // Stock that could not be spent in the minutes left makes no difference, so states only
+        // differing by it are the same.
+        let left = max_minutes - state.minute;
+        state.ore = state.ore.min(max_ore_robs * left);
+        state.clay = state.clay.min(max_clay_robs * left);
+        state.obs = state.obs.min(max_obs_robs * left);
+        if visited_states.contains(&state) {
+            continue;
+        }
+        visited_states.insert(state.clone());
+        progress.tick();
+
+        // Even a new geode robot every minute left could not beat the best so far.
+        if state.geo + state.geo_robs * left + left * (left - 1) / 2 <= max_geos {
+            continue;
+        }
+        if state.geo + state.geo_robs * left > max_geos {
+            max_geos = state.geo + state.geo_robs * left;
+            progress.improve(max_geos);
+        }
+
+        if state.minute == max_minutes {
+            if state.geo > max_geos {
+                max_geos = state.geo;
+                progress.improve(max_geos);
+            }
+            continue;
+        }
+        state.minute += 1;
+
+        // Building a robot after waiting while it was already affordable is never better than
+        // building it right away.
+        let built = [
+            state
+                .build_ore_rob(blueprint)
+                .filter(|_| state.ore_robs < max_ore_robs),
+            state
+                .build_clay_rob(blueprint)
+                .filter(|_| state.clay_robs < max_clay_robs),
+            state
+                .build_obs_rob(blueprint)
+                .filter(|_| state.obs_robs < max_obs_robs),
+        ];
+        let affordable = built.each_ref().map(Option::is_some);
+        for (kind, n) in built.into_iter().enumerate() {
+            if let Some(mut n) = n.filter(|_| !state.skipped[kind]) {
+                n.skipped = [false; 3];
+                possible_simulation_states.push(n);
+            }
+        }
+
+        if let Some(mut n) = state.build_geo_rob(blueprint) {
+            n.skipped = [false; 3];
+            possible_simulation_states.push(n);
+        } else {
+            state.skipped = affordable;
+            state.advance_factory();
+            possible_simulation_states.push(state);
+        }
+    }
+    progress.finish();
+
+    max_geos
+}
+
+pub struct Day19;
+
+impl Day for Day19 {
+    const DAY: u8 = 19;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Sol;
+    type Answer2 = Sol;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Sol {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Sol {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Sol> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Sol> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 33);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 3472);
+    }
+
+    #[test]
+    pub fn test_parse_error() {
+        let file = TEST_INPUT.replacen("obsidian robot costs 3", "obsidian robot costs x", 1);
+        let error = parse(&file).unwrap_err();
+        assert_eq!(error.line, 1);
+        assert_eq!(error.found, "'x'");
+        let error = parse("Blueprint 1: Each ore robot").unwrap_err();
+        assert_eq!((error.column, error.expected.as_str()), (28, "'costs'"));
+    }
+
+    #[test]
+    pub fn test_reference() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(reference::part_1(&parse_output), 33);
+        assert_eq!(reference::part_2(&parse_output), 3472);
+        crate::differential::assert_agrees(&Day19, 0..3, 1);
+    }
+}
@@ -0,0 +1,71 @@
+use super::{Blueprint, ParseOutput, Sol};
+
+/// What a robot of each kind costs in ore, clay and obsidian.
+type Costs = [[Sol; 3]; 4];
+
+/// Tries every order of building robots. Waiting only ever makes sense to save up for the next
+/// robot, so each step picks that robot and skips ahead to the minute it is done. Robots of a kind
+/// stop being built once there are enough to pay for any robot every minute, and a branch ends
+/// once not even a new geode robot every minute would beat the best so far. Trying geode robots
+/// first finds a good best early.
+fn search(costs: &Costs, left: Sol, robots: [Sol; 4], stock: [Sol; 4], best: &mut Sol) {
+    let geodes = stock[3] + robots[3] * left;
+    *best = (*best).max(geodes);
+    if geodes + left * (left - 1) / 2 <= *best {
+        return;
+    }
+    for (kind, cost) in costs.iter().enumerate().rev() {
+        if kind < 3 && costs.iter().all(|c| robots[kind] >= c[kind]) {
+            continue;
+        }
+        let mut wait = 0;
+        for (resource, needed) in cost.iter().enumerate() {
+            if *needed <= stock[resource] {
+                continue;
+            }
+            if robots[resource] == 0 {
+                wait = left;
+                break;
+            }
+            let missing = needed - stock[resource];
+            wait = wait.max((missing + robots[resource] - 1) / robots[resource]);
+        }
+        if wait + 1 >= left {
+            continue;
+        }
+        let mut next_stock = stock;
+        for resource in 0..4 {
+            next_stock[resource] += robots[resource] * (wait + 1);
+        }
+        for (resource, needed) in cost.iter().enumerate() {
+            next_stock[resource] -= needed;
+        }
+        let mut next_robots = robots;
+        next_robots[kind] += 1;
+        search(costs, left - wait - 1, next_robots, next_stock, best);
+    }
+}
+
+fn geodes(blueprint: &Blueprint, minutes: Sol) -> Sol {
+    let costs = [
+        [blueprint.ore_rob_ore_cost, 0, 0],
+        [blueprint.clay_rob_ore_cost, 0, 0],
+        [blueprint.obs_rob_ore_cost, blueprint.obs_rob_clay_cost, 0],
+        [blueprint.geo_rob_ore_cost, 0, blueprint.geo_rob_obs_cost],
+    ];
+    let mut best = 0;
+    search(&costs, minutes, [1, 0, 0, 0], [0; 4], &mut best);
+    best
+}
+
+pub fn part_1(blueprints: &ParseOutput) -> Sol {
+    blueprints
+        .iter()
+        .enumerate()
+        .map(|(i, blueprint)| (i as Sol + 1) * geodes(blueprint, 24))
+        .sum()
+}
+
+pub fn part_2(blueprints: &ParseOutput) -> Sol {
+    blueprints.iter().take(3).map(|b| geodes(b, 32)).product()
+}
@@ -0,0 +1,149 @@
+use crate::cycle::Cycle;
+use crate::day::Day;
+use crate::math::modulo;
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+
+pub mod reference;
+
+type Sol = i64;
+
+pub type ParseOutput = Vec<Sol>;
+const DECRYPTION_KEY: Sol = 811589153;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    let numbers: ParseOutput = source
+        .lines()
+        .map(|l| source.number(l))
+        .collect::<Result<_, _>>()?;
+    if !numbers.contains(&0) {
+        return Err(source.error(source.end(), "a 0 to count the coordinates from"));
+    }
+    Ok(numbers)
+}
+
+/// `size` numbers, at least two, with exactly one 0 among them. The others may repeat.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut numbers: Vec<Sol> = (1..size.max(2))
+        .map(|_| match rng.range(-10000..10000) {
+            0 => 10000,
+            n => n,
+        })
+        .collect();
+    numbers.insert(rng.below(numbers.len() + 1), 0);
+    numbers.iter().map(|n| format!("{}\n", n)).collect()
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Sol {
+    let mut numbers = Cycle::new(parse_output.iter().copied().enumerate().collect::<Vec<_>>());
+    mix_numbers(&mut numbers);
+    extract_solution(&numbers)
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Sol {
+    let mut numbers = Cycle::new(
+        parse_output
+            .iter()
+            .map(|n| n * DECRYPTION_KEY)
+            .enumerate()
+            .collect::<Vec<_>>(),
+    );
+    for _ in 0..10 {
+        mix_numbers(&mut numbers);
+    }
+    extract_solution(&numbers)
+}
+
+fn extract_solution(numbers: &Cycle<(usize, Sol)>) -> Sol {
+    let index_0 = numbers.position(|(_, n)| *n == 0).unwrap();
+    [1000, 2000, 3000]
+        .iter()
+        .map(|i| numbers.get(index_0 + i).1)
+        .sum()
+}
+
+/// Moves every number, in their original order, as many places as its value. The numbers are
+/// paired with their original index because values repeat.
+fn mix_numbers(numbers: &mut Cycle<(usize, Sol)>) {
+    if numbers.len() <= 1 {
+        return;
+    }
+    for original_index in 0..numbers.len() {
+        let at = numbers.position(|(i, _)| *i == original_index).unwrap();
+        numbers.turn(at);
+        let number = numbers.remove(0);
+        // the number doesn't pass itself, so it moves around a ring one shorter
+        numbers.turn(modulo(number.1, numbers.len() as Sol) as isize);
+        numbers.insert(0, number);
+    }
+}
+
+pub struct Day20;
+
+impl Day for Day20 {
+    const DAY: u8 = 20;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Sol;
+    type Answer2 = Sol;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Sol {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Sol {
+        part_2(input)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Sol> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Sol> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 3);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 1623178306);
+    }
+
+    #[test]
+    pub fn test_small_inputs() {
+        assert_eq!(parse("1\n2").unwrap_err().line, 2);
+        let parse_output = parse("0").unwrap();
+        assert_eq!((part_1(&parse_output), part_2(&parse_output)), (0, 0));
+        assert_eq!(generate(&mut Rng::new(0), 1).lines().count(), 2);
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day20, 0..20, 20);
+    }
+}
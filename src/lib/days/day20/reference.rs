@@ -0,0 +1,32 @@
+use super::{ParseOutput, Sol, DECRYPTION_KEY};
+
+/// Mixes by swapping each number with its neighbour one place at a time, around the ends of the
+/// list as if they were joined. Going once around all the others ends where it started, so only
+/// the remainder of the places count.
+fn decrypt(numbers: &ParseOutput, key: Sol, rounds: usize) -> Sol {
+    let mut mixed: Vec<(usize, Sol)> = numbers.iter().map(|n| n * key).enumerate().collect();
+    let len = mixed.len();
+    for _ in 0..rounds {
+        for original in 0..len {
+            let mut at = mixed.iter().position(|(i, _)| *i == original).unwrap();
+            let places = mixed[at].1.rem_euclid((len as Sol - 1).max(1));
+            for _ in 0..places {
+                mixed.swap(at, (at + 1) % len);
+                at = (at + 1) % len;
+            }
+        }
+    }
+    let zero = mixed.iter().position(|(_, n)| *n == 0).unwrap();
+    [1000, 2000, 3000]
+        .iter()
+        .map(|i| mixed[(zero + i) % len].1)
+        .sum()
+}
+
+pub fn part_1(numbers: &ParseOutput) -> Sol {
+    decrypt(numbers, 1, 1)
+}
+
+pub fn part_2(numbers: &ParseOutput) -> Sol {
+    decrypt(numbers, DECRYPTION_KEY, 10)
+}
@@ -0,0 +1,366 @@
+use crate::combinator::{lines, literal, number, parse_all, word, Parser};
+use crate::day::{Day, Maybe};
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+use std::collections::{HashMap, HashSet};
+
+pub mod reference;
+
+type Sol = i64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operation {
+    Add,
+    Sub,
+    Mult,
+    Div,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum P2Sol {
+    Unknown,
+    Number(Sol),
+    Operation(Box<P2Sol>, Box<P2Sol>, Operation),
+}
+
+#[derive(Debug, Clone)]
+pub enum Monkey {
+    Number(Sol),
+    Operation(String, String, Operation),
+}
+
+pub type ParseOutput = HashMap<String, Monkey>;
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    let operation = literal("+")
+        .to(Operation::Add)
+        .or(literal("-").to(Operation::Sub))
+        .or(literal("*").to(Operation::Mult))
+        .or(literal("/").to(Operation::Div));
+    let job = number().map(|n| (Monkey::Number(n), Vec::new())).or(word()
+        .then(operation)
+        .then(word())
+        .map(|((l, op), r)| (Monkey::Operation(l.into(), r.into(), op), vec![l, r])));
+    let mut references = Vec::new();
+    let monkeys = parse_all(&source, lines(word().then_ignore(literal(":")).then(job)))?
+        .into_iter()
+        .map(|(monkey_name, (monkey, r))| {
+            references.extend(r);
+            (monkey_name.to_string(), monkey)
+        })
+        .collect::<ParseOutput>();
+
+    if let Some(name) = references.into_iter().find(|r| !monkeys.contains_key(*r)) {
+        return Err(source.error(name, "a known monkey"));
+    }
+    if !monkeys.contains_key("root") {
+        return Err(source.error(source.end(), "a monkey named root"));
+    }
+    Ok(monkeys)
+}
+
+/// About `size` monkeys. Both sides of `root` are built to yell the same number with `humn`
+/// yelling its own, so that number is the answer to part 2. `humn` shows up exactly once and
+/// never as a divisor, and every division comes out even.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut monkeys = Vec::new();
+    let mut names = HashSet::from(["root".to_string(), "humn".to_string()]);
+    let operations = size.max(3) / 2;
+    let left = rng.below(operations);
+    let value = rng.range(1000..100_000);
+    let humn_left = rng.chance(0.5);
+    let l = yell(rng, value, left, humn_left, &mut names, &mut monkeys);
+    let r = yell(
+        rng,
+        value,
+        operations - 1 - left,
+        !humn_left,
+        &mut names,
+        &mut monkeys,
+    );
+    monkeys.push(format!("root: {} + {}", l, r));
+    rng.shuffle(&mut monkeys);
+    monkeys.iter().map(|m| format!("{}\n", m)).collect()
+}
+
+/// Adds monkeys that end up yelling `value` after `operations` operations and returns the name
+/// of the one yelling it.
+fn yell(
+    rng: &mut Rng,
+    value: Sol,
+    operations: usize,
+    humn: bool,
+    names: &mut HashSet<String>,
+    monkeys: &mut Vec<String>,
+) -> String {
+    let name = match humn && operations == 0 {
+        true => "humn".to_string(),
+        false => loop {
+            let name: String = (0..4)
+                .map(|_| (b'a' + rng.below(26) as u8) as char)
+                .collect();
+            if names.insert(name.clone()) {
+                break name;
+            }
+        },
+    };
+    if operations == 0 {
+        monkeys.push(format!("{}: {}", name, value));
+        return name;
+    }
+
+    let factor = (2..10).rev().find(|f| value % f == 0);
+    let (a, op, b) = match (rng.below(4), factor) {
+        (0, _) if value >= 2 => {
+            let a = rng.range(1..value);
+            (a, '+', value - a)
+        }
+        (1, Some(f)) => (value / f, '*', f),
+        (2, _) if value < 1_000_000_000 => {
+            let b = rng.range(2..6);
+            (value * b, '/', b)
+        }
+        _ => {
+            let b = rng.range(1..100);
+            (value + b, '-', b)
+        }
+    };
+    let left = rng.below(operations);
+    // The divisor has to stay a constant for part 2.
+    let humn_left = humn && (op == '/' || rng.chance(0.5));
+    let a = yell(rng, a, left, humn_left, names, monkeys);
+    let b = yell(
+        rng,
+        b,
+        operations - 1 - left,
+        humn && !humn_left,
+        names,
+        monkeys,
+    );
+    monkeys.push(format!("{}: {} {} {}", name, a, op, b));
+    name
+}
+
+pub fn part_1(monkeys: &ParseOutput) -> Sol {
+    let sorted = top_sort_monkeys(monkeys);
+    let mut solution = HashMap::<String, Sol>::new();
+    for (name, monkey) in sorted {
+        match monkey {
+            Monkey::Number(n) => {
+                solution.insert(name, n);
+            }
+            Monkey::Operation(spring_1, spring_2, op) => {
+                let sol_spring_1 = solution.get(&spring_1).unwrap();
+                let sol_spring_2 = solution.get(&spring_2).unwrap();
+                solution.insert(
+                    name,
+                    match op {
+                        Operation::Add => sol_spring_1 + sol_spring_2,
+                        Operation::Sub => sol_spring_1 - sol_spring_2,
+                        Operation::Mult => sol_spring_1 * sol_spring_2,
+                        Operation::Div => sol_spring_1 / sol_spring_2,
+                    },
+                );
+            }
+        }
+    }
+
+    *solution.get("root").unwrap()
+}
+
+pub fn part_2(monkeys: &ParseOutput) -> Option<Sol> {
+    let sorted = top_sort_monkeys(monkeys);
+    let mut solution = HashMap::<String, P2Sol>::new();
+
+    for (name, monkey) in sorted {
+        match monkey {
+            Monkey::Number(n) => {
+                if name == "humn" {
+                    solution.insert(name, P2Sol::Unknown);
+                } else {
+                    solution.insert(name, P2Sol::Number(n));
+                }
+            }
+            Monkey::Operation(spring_1, spring_2, op) => {
+                let p2sol_1 = solution.remove(&spring_1).unwrap();
+                let p2sol_2 = solution.remove(&spring_2).unwrap();
+                if let (P2Sol::Number(n1), P2Sol::Number(n2)) = (&p2sol_1, &p2sol_2) {
+                    solution.insert(
+                        name,
+                        P2Sol::Number(match op {
+                            Operation::Add => n1 + n2,
+                            Operation::Sub => n1 - n2,
+                            Operation::Mult => n1 * n2,
+                            Operation::Div => n1 / n2,
+                        }),
+                    );
+                } else {
+                    solution.insert(
+                        name,
+                        P2Sol::Operation(Box::from(p2sol_1), Box::from(p2sol_2), op),
+                    );
+                }
+            }
+        }
+    }
+
+    let Some(P2Sol::Operation(p1, p2, _)) = solution.remove("root") else {
+        return None;
+    };
+    let (unknown, monkey_n) = match (*p1, *p2) {
+        (P2Sol::Number(n), unknown) | (unknown, P2Sol::Number(n)) => (unknown, n),
+        _ => return None,
+    };
+    crate::debug!("{} = {}", get_fun(unknown.clone()), monkey_n);
+    solve(unknown, monkey_n)
+}
+
+/// The number humn has to yell for `unknown` to come out as `target`, undoing its operations
+/// from the outside in. `None` if humn is not in `unknown`, is on both sides of an operation,
+/// or no whole number works.
+fn solve(unknown: P2Sol, target: Sol) -> Option<Sol> {
+    match unknown {
+        P2Sol::Unknown => Some(target),
+        P2Sol::Number(_) => None,
+        P2Sol::Operation(p1, p2, op) => match (*p1, *p2) {
+            (P2Sol::Number(n), p2) => solve(
+                p2,
+                match op {
+                    Operation::Add => target.checked_sub(n)?,
+                    Operation::Sub => n.checked_sub(target)?,
+                    Operation::Mult => exact_div(target, n)?,
+                    Operation::Div => exact_div(n, target)?,
+                },
+            ),
+            (p1, P2Sol::Number(n)) => solve(
+                p1,
+                match op {
+                    Operation::Add => target.checked_sub(n)?,
+                    Operation::Sub => target.checked_add(n)?,
+                    Operation::Mult => exact_div(target, n)?,
+                    Operation::Div => target.checked_mul(n)?,
+                },
+            ),
+            _ => None,
+        },
+    }
+}
+
+fn exact_div(a: Sol, b: Sol) -> Option<Sol> {
+    (b != 0 && a % b == 0).then(|| a / b)
+}
+
+fn get_fun(unknown: P2Sol) -> String {
+    match unknown {
+        P2Sol::Unknown => "n".into(),
+        P2Sol::Number(n) => format!("{}", n),
+        P2Sol::Operation(p1, p2, op) => match op {
+            Operation::Add => format!("({}+{})", get_fun(*p1), get_fun(*p2)),
+            Operation::Sub => format!("({}-{})", get_fun(*p1), get_fun(*p2)),
+            Operation::Mult => format!("({}*{})", get_fun(*p1), get_fun(*p2)),
+            Operation::Div => format!("({}/{})", get_fun(*p1), get_fun(*p2)),
+        },
+    }
+}
+
+fn top_sort_monkeys(monkeys: &ParseOutput) -> Vec<(String, Monkey)> {
+    let mut spring_to_source: HashMap<String, String> = HashMap::new();
+    let mut springs: Vec<(String, Monkey)> = monkeys
+        .iter()
+        .filter_map(|(n, m)| {
+            if let Monkey::Number(_) = m {
+                Some((n.clone(), m.clone()))
+            } else {
+                None
+            }
+        })
+        .collect();
+    let mut sorted: Vec<(String, Monkey)> = Vec::new();
+    for (name, m) in monkeys.iter() {
+        match m {
+            Monkey::Number(_) => {}
+            Monkey::Operation(n1, n2, _) => {
+                assert_eq!(spring_to_source.insert(n1.into(), name.into()), None);
+                assert_eq!(spring_to_source.insert(n2.into(), name.into()), None);
+            }
+        }
+    }
+
+    while let Some(spring) = springs.pop() {
+        if let Some((_, source_id)) = spring_to_source.remove_entry(&spring.0) {
+            if let Some(m) = monkeys.get(&source_id) {
+                if let Monkey::Operation(source_1, source_2, _) = m {
+                    if !spring_to_source.contains_key(source_1)
+                        && !spring_to_source.contains_key(source_2)
+                    {
+                        springs.push((source_id.clone(), m.clone()));
+                    }
+                }
+            }
+        }
+        sorted.push(spring);
+    }
+    sorted
+}
+
+pub struct Day21;
+
+impl Day for Day21 {
+    const DAY: u8 = 21;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Sol;
+    type Answer2 = Maybe<Sol>;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Sol {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Maybe<Sol> {
+        Maybe(part_2(input))
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Sol> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Maybe<Sol>> {
+        Some(Maybe(reference::part_2(input)))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 152);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), Some(301));
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day21, 0..20, 20);
+    }
+}
@@ -0,0 +1,64 @@
+use super::{Monkey, Operation, ParseOutput, Sol};
+use crate::math::gcd;
+
+/// What a monkey yells as a fraction linear in what humn yells, `(a * humn + b) / d`.
+#[derive(Debug, Clone, Copy)]
+struct Linear {
+    a: Sol,
+    b: Sol,
+    d: Sol,
+}
+
+impl Linear {
+    fn new(a: Sol, b: Sol, d: Sol) -> Linear {
+        let divisor = gcd(gcd(a.unsigned_abs(), b.unsigned_abs()), d.unsigned_abs()) as Sol;
+        let divisor = divisor * d.signum();
+        Linear {
+            a: a / divisor,
+            b: b / divisor,
+            d: d / divisor,
+        }
+    }
+
+    fn constant(n: Sol) -> Linear {
+        Linear::new(0, n, 1)
+    }
+}
+
+/// Works out what `name` yells by asking the monkeys it waits for. With `unknown` humn yells an
+/// unknown number instead of its own. Products and quotients of two unknowns can not happen, as
+/// humn is only waited for by one monkey.
+fn yell(monkeys: &ParseOutput, name: &str, unknown: bool) -> Linear {
+    if name == "humn" && unknown {
+        return Linear::new(1, 0, 1);
+    }
+    let (left, right, operation) = match &monkeys[name] {
+        Monkey::Number(n) => return Linear::constant(*n),
+        Monkey::Operation(left, right, operation) => (left, right, operation),
+    };
+    let (l, r) = (yell(monkeys, left, unknown), yell(monkeys, right, unknown));
+    match operation {
+        Operation::Add => Linear::new(l.a * r.d + r.a * l.d, l.b * r.d + r.b * l.d, l.d * r.d),
+        Operation::Sub => Linear::new(l.a * r.d - r.a * l.d, l.b * r.d - r.b * l.d, l.d * r.d),
+        Operation::Mult if l.a == 0 => Linear::new(r.a * l.b, r.b * l.b, r.d * l.d),
+        Operation::Mult => Linear::new(l.a * r.b, l.b * r.b, l.d * r.d),
+        Operation::Div => Linear::new(l.a * r.d, l.b * r.d, l.d * r.b),
+    }
+}
+
+pub fn part_1(monkeys: &ParseOutput) -> Sol {
+    let root = yell(monkeys, "root", false);
+    root.b / root.d
+}
+
+/// Solves `left = right` for humn, if humn makes a difference to it.
+pub fn part_2(monkeys: &ParseOutput) -> Option<Sol> {
+    let Monkey::Operation(left, right, _) = &monkeys["root"] else {
+        return None;
+    };
+    let (l, r) = (yell(monkeys, left, true), yell(monkeys, right, true));
+    match l.a * r.d - r.a * l.d {
+        0 => None,
+        a => Some((r.b * l.d - l.b * r.d) / a),
+    }
+}
@@ -0,0 +1,328 @@
+use crate::animation::{Cell, Frames, Viewport};
+use crate::day::{Day, NoPart2};
+use crate::math::modulo;
+use crate::parse::{ParseError, Source};
+use crate::point::{Direction, Point2};
+use crate::random::Rng;
+
+pub mod reference;
+
+type Sol = i32;
+
+pub type Walls = Vec<Sol>;
+pub type Line = (Sol, Sol, Walls);
+pub type Row = Line;
+pub type Column = Line;
+pub type Grid = (Vec<Row>, Vec<Column>, Sol, Sol);
+pub type ParseOutput = (Grid, Vec<Move>);
+#[derive(Debug, Clone)]
+pub enum Move {
+    Steps(Sol),
+    Turn(Sol),
+}
+
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    let (gg, mm) = match source.sections()[..] {
+        [gg, mm] => (gg, mm.trim_end()),
+        _ => return Err(source.error(source.end(), "a map and a path separated by a blank line")),
+    };
+    let lines = gg
+        .lines()
+        .map(|l| match l.find(|c| !matches!(c, ' ' | '.' | '#')) {
+            Some(i) => Err(source.error(&l[i..], "' ', '.' or '#'")),
+            None => Ok(l.chars().collect()),
+        })
+        .collect::<Result<Vec<Vec<char>>, ParseError>>()?;
+    let grid_max_x = lines.iter().map(|l| l.len()).max().unwrap() - 1;
+    let (mut rows, mut columns) = (Vec::<Row>::new(), Vec::<Column>::new());
+    for chars in lines.iter() {
+        let (mut started, mut start, mut end, mut walls) = (false, 0, 0, Vec::<Sol>::new());
+        for (x, c) in chars.iter().enumerate() {
+            if *c == '#' {
+                walls.push(x as Sol)
+            }
+            if *c == '.' || *c == '#' {
+                if !started {
+                    started = true;
+                    start = x;
+                }
+                end = x;
+            }
+        }
+        rows.push((start as Sol, end as Sol, walls));
+    }
+
+    for x in 0..=grid_max_x {
+        let (mut started, mut start, mut end, mut walls) = (false, 0, 0, Vec::<Sol>::new());
+        for (y, l) in lines.iter().enumerate().filter(|(_, l)| x < l.len()) {
+            let c = l[x];
+            if c == '#' {
+                walls.push(y as Sol)
+            }
+            if c == '.' || c == '#' {
+                if !started {
+                    started = true;
+                    start = y;
+                }
+                end = y;
+            }
+        }
+        columns.push((start as Sol, end as Sol, walls));
+    }
+
+    let mut moves = Vec::new();
+    let mut steps_start = None;
+    for (i, c) in mm.char_indices() {
+        if c.is_ascii_digit() {
+            steps_start.get_or_insert(i);
+            continue;
+        }
+        if let Some(start) = steps_start.take() {
+            moves.push(Move::Steps(source.number(&mm[start..i])?));
+        }
+        moves.push(match c {
+            'L' => Move::Turn(-1),
+            'R' => Move::Turn(1),
+            _ => return Err(source.error(&mm[i..], "a number, 'L' or 'R'")),
+        });
+    }
+    if let Some(start) = steps_start {
+        moves.push(Move::Steps(source.number(&mm[start..])?));
+    }
+
+    Ok((
+        (rows, columns, grid_max_x as Sol, (lines.len() - 1) as Sol),
+        moves,
+    ))
+}
+
+/// Ways to fold out a cube, by faces: the one of the puzzle inputs, the one of the example and a
+/// few others.
+const NETS: [&[&str]; 5] = [
+    &[" ##", " # ", "## ", "#  "],
+    &["  # ", "### ", "  ##"],
+    &[" # ", "###", " # ", " # "],
+    &["##  ", " ## ", "  ##"],
+    &["#   ", "####", "   #"],
+];
+
+/// A cube folded out in one of `NETS`, with faces `size` tiles wide, and a path of about `size`
+/// moves. The first tile is always open.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let net = *rng.choose(&NETS);
+    let side = size.max(2);
+    let mut file = String::new();
+    for (y, faces) in net.iter().enumerate() {
+        for row in 0..side {
+            let mut line = String::new();
+            for face in faces.chars() {
+                for _ in 0..side {
+                    let first = y == 0 && row == 0 && line.trim().is_empty();
+                    line.push(match face {
+                        '#' if !first && rng.chance(0.1) => '#',
+                        '#' => '.',
+                        _ => ' ',
+                    });
+                }
+            }
+            file.push_str(line.trim_end());
+            file.push('\n');
+        }
+    }
+
+    file.push('\n');
+    for i in 0..size.max(1) {
+        if i > 0 {
+            file.push(*rng.choose(&['L', 'R']));
+        }
+        file.push_str(&rng.range(1..51).to_string());
+    }
+    file.push('\n');
+    file
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Sol {
+    walk(parse_output, |_, _| true)
+}
+
+/// Follows the path and calls `on_move` after every move, stopping early once it returns false.
+fn walk<F: FnMut(Point2, Direction) -> bool>(parse_output: &ParseOutput, mut on_move: F) -> Sol {
+    let ((rows, columns, _, _), moves) = parse_output;
+    let mut dir = Direction::Right;
+    let mut pos = Point2::new(0, rows[0].0);
+
+    for m in moves {
+        match m {
+            Move::Steps(n) => {
+                let sign = dir.offset();
+                if dir.is_horizontal() {
+                    pos.x = calc_new_pos(rows, pos.y, pos.x, n, &sign.x);
+                } else {
+                    pos.y = calc_new_pos(columns, pos.x, pos.y, n, &sign.y);
+                }
+            }
+            Move::Turn(t) => dir = dir.turn(*t),
+        }
+        if !on_move(pos, dir) {
+            break;
+        }
+    }
+    calc_solution(dir, pos)
+}
+
+fn calc_new_pos(line: &[Line], i: Sol, pos: Sol, n: &Sol, sign: &Sol) -> Sol {
+    let (start, end, walls) = &line[i as usize];
+    let real_n = move_amount(pos, *sign, *n, walls, *start, *end);
+    move_and_mod_to_range(*start, *end, pos, real_n)
+}
+
+fn calc_solution(dir: Direction, pos: Point2) -> i32 {
+    (pos.y + 1) * 1000
+        + (pos.x + 1) * 4
+        + match dir {
+            Direction::Up => 3,
+            Direction::Left => 2,
+            Direction::Right => 0,
+            Direction::Down => 1,
+        }
+}
+
+fn move_amount(pos: Sol, dir: Sol, n: Sol, walls: &[Sol], start: Sol, end: Sol) -> Sol {
+    if walls.is_empty() {
+        return n * dir;
+    }
+
+    let d = distance_to_wall(pos, dir, walls, start, end);
+    if d.abs() < n {
+        d
+    } else {
+        n * dir
+    }
+}
+
+fn distance_to_wall(pos: Sol, dir: Sol, walls: &[Sol], start: Sol, end: Sol) -> Sol {
+    let len = end - start + 1;
+    match dir {
+        1 => {
+            let w = if let Some(w) = walls.iter().find(|p| **p > pos) {
+                *w - start
+            } else {
+                walls[0] - start
+            };
+            let p = pos - start;
+            if w < p {
+                (len - p) + w - 1
+            } else {
+                w - p - 1
+            }
+        }
+        -1 => {
+            let w = if let Some(w) = walls.iter().rev().find(|p| **p < pos) {
+                *w - start
+            } else {
+                walls[walls.len() - 1] - start
+            };
+            let p = pos - start;
+            if w > p {
+                -((len - w) + p) + 1
+            } else {
+                w - p + 1
+            }
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn move_and_mod_to_range(start: Sol, end: Sol, pos: Sol, n: Sol) -> Sol {
+    let p = pos - start;
+    let len = end - start + 1;
+    start + modulo(p + n, len)
+}
+
+pub fn animate(parse_output: &ParseOutput, frames: &mut dyn Frames) {
+    let ((rows, _, max_x, max_y), _) = parse_output;
+    let bounds = Viewport::new(0, 0, *max_y, *max_x);
+    walk(parse_output, |position, dir| {
+        let position = position.into();
+        frames.frame(&bounds, position, &format!("{:?}", dir), &mut |y, x| {
+            let (start, end, walls) = &rows[y as usize];
+            if (y, x) == position {
+                Cell::Actor
+            } else if x < *start || x > *end {
+                Cell::Blank
+            } else if walls.contains(&x) {
+                Cell::Wall
+            } else {
+                Cell::Empty
+            }
+        })
+    });
+}
+
+pub struct Day22;
+
+impl Day for Day22 {
+    const DAY: u8 = 22;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+    const ANIMATED: bool = true;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Sol;
+    // Folding the map into a cube is not solved yet.
+    type Answer2 = NoPart2;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Sol {
+        part_1(input)
+    }
+
+    fn part_2(_input: &ParseOutput, _params: &()) -> NoPart2 {
+        NoPart2
+    }
+
+    fn animate(input: &ParseOutput, _params: &(), frames: &mut dyn Frames) {
+        animate(input, frames)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Sol> {
+        Some(reference::part_1(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 6032);
+    }
+
+    #[test]
+    pub fn test_parse_error() {
+        let error = parse("..#\n.x.\n\n10R5").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = parse("...\n\n10R5X").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 5));
+        assert!(parse("...").is_err());
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day22, 0..10, 4);
+    }
+}
@@ -0,0 +1,41 @@
+use super::{Move, ParseOutput, Sol};
+use crate::point::{Direction, Point2};
+
+/// Walks the path one tile at a time. Stepping off the map wraps around to the last tile in the
+/// other direction.
+pub fn part_1(parse_output: &ParseOutput) -> Sol {
+    let ((rows, _, _, _), moves) = parse_output;
+    let tile = |p: Point2| {
+        let (start, end, walls) = rows.get(usize::try_from(p.y).ok()?)?;
+        (*start..=*end).contains(&p.x).then(|| walls.contains(&p.x))
+    };
+    let mut position = Point2::new(0, rows[0].0);
+    let mut facing = Direction::Right;
+    for m in moves {
+        match m {
+            Move::Turn(t) => facing = facing.turn(*t),
+            Move::Steps(n) => {
+                for _ in 0..*n {
+                    let mut next = position + facing.offset();
+                    if tile(next).is_none() {
+                        next = position;
+                        while tile(next - facing.offset()).is_some() {
+                            next -= facing.offset();
+                        }
+                    }
+                    if tile(next) == Some(true) {
+                        break;
+                    }
+                    position = next;
+                }
+            }
+        }
+    }
+    let facing = match facing {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    };
+    1000 * (position.y + 1) + 4 * (position.x + 1) + facing
+}
@@ -0,0 +1,233 @@
+use crate::animation::{Cell, Frames, Viewport};
+use crate::cycle::Cycle;
+use crate::day::Day;
+use crate::parse::{ParseError, Source};
+use crate::point::{Direction, Point2};
+use crate::random::Rng;
+
+type Sol = i32;
+use std::collections::{HashMap, HashSet};
+
+pub mod reference;
+
+pub type Elf = Point2;
+pub type ParseOutput = HashSet<Elf>;
+type Proposals = HashMap<Point2, (Elf, Sol)>;
+
+/// The order in which the elves consider moving during the first round.
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    let mut elves = ParseOutput::new();
+    for (y, l) in source.lines().enumerate() {
+        for (x, c) in l.char_indices() {
+            match c {
+                '#' => {
+                    elves.insert(Point2::new(y as Sol, x as Sol));
+                }
+                '.' => {}
+                _ => return Err(source.error(&l[x..], "'#' or '.'")),
+            }
+        }
+    }
+    Ok(elves)
+}
+
+/// A square scan `size` tiles wide with about half of them taken by elves, at least one.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(1);
+    let mut tiles: Vec<Vec<char>> = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    tiles[side / 2][side / 2] = '#';
+    tiles
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Sol {
+    let mut elves = parse_output.clone();
+    let mut dirs = Cycle::new(DIRECTIONS);
+    let mut proposals = Proposals::new();
+
+    for _ in 0..10 {
+        move_elves(&mut elves, &mut dirs, &mut proposals);
+    }
+
+    let (min, max) = min_max_elves(&elves);
+    (max.x - min.x + 1) * (max.y - min.y + 1) - elves.len() as Sol
+}
+
+pub fn part_2(parse_output: &ParseOutput) -> Sol {
+    let mut elves = parse_output.clone();
+    let mut dirs = Cycle::new(DIRECTIONS);
+    let mut proposals = Proposals::new();
+
+    let mut i = 0;
+    while move_elves(&mut elves, &mut dirs, &mut proposals) {
+        i += 1;
+    }
+    i + 1
+}
+
+pub fn animate(parse_output: &ParseOutput, frames: &mut dyn Frames) {
+    let mut elves = parse_output.clone();
+    let mut dirs = Cycle::new(DIRECTIONS);
+    let mut proposals = Proposals::new();
+
+    let mut i = 0;
+    loop {
+        let (min, max) = min_max_elves(&elves);
+        let bounds = Viewport::new(min.y - 1, min.x - 1, max.y + 1, max.x + 1);
+        let caption = format!("round {}", i);
+        let mut cell = |y, x| {
+            if elves.contains(&Point2::new(y, x)) {
+                Cell::Actor
+            } else {
+                Cell::Empty
+            }
+        };
+        if !frames.frame(&bounds, bounds.center(), &caption, &mut cell)
+            || !move_elves(&mut elves, &mut dirs, &mut proposals)
+        {
+            break;
+        }
+        i += 1;
+    }
+}
+
+/// Plays one round and turns `dirs` so that the next round starts with the following direction.
+fn move_elves(
+    elves: &mut HashSet<Elf>,
+    dirs: &mut Cycle<Direction>,
+    proposals: &mut Proposals,
+) -> bool {
+    for elf in elves.iter() {
+        if !has_neighbours(elves, *elf) {
+            continue;
+        }
+        for dir in dirs.iter() {
+            if propose(elves, proposals, *elf, *dir) {
+                break;
+            }
+        }
+    }
+    dirs.turn(1);
+    if proposals.is_empty() {
+        return false;
+    }
+    for (target, (elf, count)) in proposals.drain() {
+        if count > 1 {
+            continue;
+        }
+        if elves.remove(&elf) {
+            elves.insert(target);
+        }
+    }
+    true
+}
+
+fn has_neighbours(elves: &HashSet<Elf>, elf: Elf) -> bool {
+    elf.neighbours_8().iter().any(|n| elves.contains(n))
+}
+
+fn min_max_elves(elves: &HashSet<Elf>) -> (Point2, Point2) {
+    elves.iter().fold(
+        (
+            Point2::new(Sol::MAX, Sol::MAX),
+            Point2::new(Sol::MIN, Sol::MIN),
+        ),
+        |(min, max), elf| (min.min(*elf), max.max(*elf)),
+    )
+}
+
+/// Proposes moving `elf` one step in `dir` if that cell and both cells beside it are free.
+fn propose(elves: &HashSet<Elf>, proposals: &mut Proposals, elf: Elf, dir: Direction) -> bool {
+    let target = elf + dir.offset();
+    if !elves.contains(&(target + dir.turn_left().offset()))
+        && !elves.contains(&target)
+        && !elves.contains(&(target + dir.turn_right().offset()))
+    {
+        proposals.entry(target).or_insert((elf, 0)).1 += 1;
+        return true;
+    }
+    false
+}
+
+pub struct Day23;
+
+impl Day for Day23 {
+    const DAY: u8 = 23;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+    const ANIMATED: bool = true;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Sol;
+    type Answer2 = Sol;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Sol {
+        part_1(input)
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Sol {
+        part_2(input)
+    }
+
+    fn animate(input: &ParseOutput, _params: &(), frames: &mut dyn Frames) {
+        animate(input, frames)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Sol> {
+        Some(reference::part_1(input))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Sol> {
+        Some(reference::part_2(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), 110);
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), 20);
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day23, 0..10, 8);
+    }
+}
@@ -0,0 +1,60 @@
+use super::{Elf, ParseOutput, Sol};
+use std::collections::{HashMap, HashSet};
+
+/// The positions an elf checks for each direction, starting with the one it moves to.
+const LOOKS: [[(Sol, Sol); 3]; 4] = [
+    [(-1, 0), (-1, -1), (-1, 1)],
+    [(1, 0), (1, -1), (1, 1)],
+    [(0, -1), (-1, -1), (1, -1)],
+    [(0, 1), (-1, 1), (1, 1)],
+];
+
+/// Plays round number `round` from 0, returning whether any elf moved.
+fn play(elves: &mut HashSet<Elf>, round: usize) -> bool {
+    let taken = |elf: &Elf, (y, x): (Sol, Sol)| elves.contains(&Elf::new(elf.y + y, elf.x + x));
+    let mut proposals: HashMap<Elf, Vec<Elf>> = HashMap::new();
+    for elf in elves.iter() {
+        if LOOKS.iter().flatten().all(|look| !taken(elf, *look)) {
+            continue;
+        }
+        for i in 0..4 {
+            let looks = LOOKS[(round + i) % 4];
+            if looks.iter().all(|look| !taken(elf, *look)) {
+                let target = Elf::new(elf.y + looks[0].0, elf.x + looks[0].1);
+                proposals.entry(target).or_default().push(*elf);
+                break;
+            }
+        }
+    }
+    let mut moved = false;
+    for (target, proposed_by) in proposals {
+        if let [elf] = proposed_by[..] {
+            elves.remove(&elf);
+            elves.insert(target);
+            moved = true;
+        }
+    }
+    moved
+}
+
+pub fn part_1(elves: &ParseOutput) -> Sol {
+    let mut elves = elves.clone();
+    for round in 0..10 {
+        play(&mut elves, round);
+    }
+    let ys = elves.iter().map(|e| e.y);
+    let xs = elves.iter().map(|e| e.x);
+    let height = ys.clone().max().unwrap() - ys.min().unwrap() + 1;
+    let width = xs.clone().max().unwrap() - xs.min().unwrap() + 1;
+    height * width - elves.len() as Sol
+}
+
+/// The number of the first round in which no elf moves, counting from 1.
+pub fn part_2(elves: &ParseOutput) -> Sol {
+    let mut elves = elves.clone();
+    let mut round = 0;
+    while play(&mut elves, round) {
+        round += 1;
+    }
+    round as Sol + 1
+}
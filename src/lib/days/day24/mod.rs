@@ -0,0 +1,356 @@
+use crate::animation::{Cell, Frames, Viewport};
+use crate::day::{Day, Maybe};
+use crate::grid::Grid;
+use crate::parse::{ParseError, Source};
+use crate::point::{Direction, Point2};
+
+type Sol = i32;
+use crate::math::{lcm, manhattan_distance, modulo};
+use crate::random::Rng;
+use crate::search::{self, Graph};
+use std::fmt::{Display, Formatter};
+
+pub mod reference;
+
+pub type StormMask = u8;
+
+pub const TOP: StormMask = 0b1000;
+pub const RIGHT: StormMask = 0b0100;
+pub const LEFT: StormMask = 0b0010;
+pub const DOWN: StormMask = 0b0001;
+
+/// The expedition may move up, down, left or right, or wait where it is.
+const MOVES: [Point2; 5] = [
+    Direction::Up.offset(),
+    Direction::Down.offset(),
+    Direction::Left.offset(),
+    Direction::Right.offset(),
+    Point2::ORIGIN,
+];
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum FieldType {
+    Rock,
+    Valley(StormMask),
+}
+
+impl FieldType {
+    fn is_full(&self) -> bool {
+        match self {
+            FieldType::Rock => true,
+            FieldType::Valley(storm) => *storm > 0,
+        }
+    }
+
+    fn symbol(&self) -> char {
+        match *self {
+            FieldType::Rock => '#',
+            FieldType::Valley(0) => '.',
+            FieldType::Valley(TOP) => '^',
+            FieldType::Valley(RIGHT) => '>',
+            FieldType::Valley(DOWN) => 'v',
+            FieldType::Valley(LEFT) => '<',
+            FieldType::Valley(n) => std::char::from_digit(n.count_ones(), 10).unwrap(),
+        }
+    }
+}
+
+impl Display for FieldType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+pub type Point = (usize, usize);
+pub type Storm = (Point, StormMask);
+pub type ParseOutput = (usize, usize, Point, Point, Vec<Storm>);
+
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    let lines = source.lines().collect::<Vec<&str>>();
+    let (width, height) = (lines.first().map_or(0, |l| l.len()), lines.len());
+    let mut start = None;
+    let mut end = None;
+    let mut v: Vec<Storm> = Vec::new();
+    for (y, l) in lines.iter().enumerate() {
+        if l.len() != width {
+            return Err(source.error(l, format!("a row of {} fields", width)));
+        }
+        for (x, c) in l.char_indices() {
+            let wall = y == 0 || y == height - 1 || x == 0 || x == width - 1;
+            let opening = if y == 0 { &mut start } else { &mut end };
+            match c {
+                '.' if !wall => {}
+                '.' if x != 0 && x != width - 1 && opening.is_none() => {
+                    *opening = Some((y, x));
+                }
+                '#' if wall => {}
+                _ if wall => return Err(source.error(&l[x..], "'#'")),
+                '>' => v.push(((y, x), RIGHT)),
+                '<' => v.push(((y, x), LEFT)),
+                '^' => v.push(((y, x), TOP)),
+                'v' => v.push(((y, x), DOWN)),
+                _ => return Err(source.error(&l[x..], "'.', '>', '<', '^' or 'v'")),
+            }
+        }
+    }
+
+    match (start, end) {
+        _ if height < 3 => Err(source.error(source.end(), "a row between the walls")),
+        (Some(start), Some(end)) => Ok((width, height, start, end, v)),
+        (None, _) => Err(source.error(source.end(), "an opening in the top wall")),
+        _ => Err(source.error(source.end(), "an opening in the bottom wall")),
+    }
+}
+
+/// A valley `size` tiles wide, at least two, and a quarter as high, with blizzards on about a
+/// third of the tiles. Like in the puzzle inputs no blizzard moves up or down the columns of the
+/// openings, which would leave the valley.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(2);
+    let height = (width / 4).max(2);
+    let mut file = format!("#.{}\n", "#".repeat(width));
+    for _ in 0..height {
+        file.push('#');
+        for x in 1..=width {
+            let vertical = x != 1 && x != width;
+            file.push(match rng.below(18) {
+                0 | 1 => '>',
+                2 | 3 => '<',
+                4 if vertical => '^',
+                5 if vertical => 'v',
+                _ => '.',
+            });
+        }
+        file.push_str("#\n");
+    }
+    file.push_str(&format!("{}.#\n", "#".repeat(width)));
+    file
+}
+
+/// The fewest minutes to cross the valley, if the storms ever let the expedition through.
+pub fn part_1(parse_output: &ParseOutput) -> Option<Sol> {
+    let (width, height, start, end, storms_start) = parse_output;
+    let (storm_states, _) =
+        generate_possible_storm_states(width, height, &start.1, &end.1, storms_start);
+    trip(&storm_states, *start, *end, 0).map(|minute| minute as Sol)
+}
+
+/// There, back to the start for the snacks and there again.
+pub fn part_2(parse_output: &ParseOutput) -> Option<Sol> {
+    let (width, height, start, end, storms_start) = parse_output;
+    let (storm_states, _) =
+        generate_possible_storm_states(width, height, &start.1, &end.1, storms_start);
+    let trip = |from, to, minute| trip(&storm_states, from, to, minute);
+    trip(*start, *end, 0)
+        .and_then(|minute| trip(*end, *start, minute))
+        .and_then(|minute| trip(*start, *end, minute))
+        .map(|minute| minute as Sol)
+}
+
+/// The minute of arriving at `to` when leaving `from` at `minute`, if the storms ever let the
+/// expedition through.
+fn trip(storm_states: &[Grid<FieldType>], from: Point, to: Point, minute: usize) -> Option<usize> {
+    let valley = Valley {
+        storm_states,
+        cycle_count: storm_states.len(),
+        end: to,
+    };
+    let search = search::astar(
+        &valley,
+        [(from, minute % valley.cycle_count)],
+        |(point, _)| *point == valley.end,
+    );
+    crate::debug!("visited {} states", search.visited());
+    search.cost().map(|minutes| minute + minutes as usize)
+}
+
+/// The expedition's position and the minute modulo `cycle_count`, after which the storms repeat.
+type State = (Point, usize);
+
+struct Valley<'a> {
+    storm_states: &'a [Grid<FieldType>],
+    cycle_count: usize,
+    end: Point,
+}
+
+impl Graph for Valley<'_> {
+    type Node = State;
+    type Cost = u32;
+
+    fn neighbours(&self, (point, minute): &State) -> Vec<(State, u32)> {
+        let next_minute = (minute + 1) % self.cycle_count;
+        let storm_state = &self.storm_states[next_minute];
+        MOVES
+            .iter()
+            .filter_map(|d| storm_state.offset(*point, (*d).into()))
+            .filter(|p| !storm_state[*p].is_full())
+            .map(|p| ((p, next_minute), 1))
+            .collect()
+    }
+
+    fn heuristic(&self, (point, _): &State) -> u32 {
+        manhattan_distance(*point, self.end)
+    }
+}
+
+pub fn animate(
+    (width, height, (start_y, start_x), (_, end_x), storms_start): &ParseOutput,
+    frames: &mut dyn Frames,
+) {
+    let (storm_states, cycle_count) =
+        generate_possible_storm_states(width, height, start_x, end_x, storms_start);
+    let bounds = Viewport::new(0, 0, *height as Sol - 1, *width as Sol - 1);
+    let start = (*start_y as Sol, *start_x as Sol);
+    for (minute, grid) in storm_states.iter().enumerate() {
+        let caption = format!("minute {} of {}", minute, cycle_count);
+        let mut cell = |y, x| match grid[(y as usize, x as usize)] {
+            _ if (y, x) == start => Cell::Actor,
+            FieldType::Rock => Cell::Wall,
+            FieldType::Valley(0) => Cell::Empty,
+            field => Cell::Marker(field.symbol()),
+        };
+        if !frames.frame(&bounds, start, &caption, &mut cell) {
+            break;
+        }
+    }
+}
+
+fn generate_possible_storm_states(
+    width: &usize,
+    height: &usize,
+    start_x: &usize,
+    end_x: &usize,
+    storms_start: &Vec<Storm>,
+) -> (Vec<Grid<FieldType>>, usize) {
+    let grid = generate_empty_valley(width, height, start_x, end_x);
+
+    let (h, w) = (*height as Sol - 2, *width as Sol - 2);
+    let cycle_count = lcm(h as u64, w as u64) as usize;
+    let mut possible_storm_states = vec![grid; cycle_count];
+
+    for (i, storm_state) in possible_storm_states.iter_mut().enumerate() {
+        for ((y, x), storm_mask) in storms_start {
+            let (s_y, s_x) = match *storm_mask {
+                TOP => (move_and_mod_to_range(1, h, *y as Sol, -(i as Sol)), *x),
+                RIGHT => (*y, move_and_mod_to_range(1, w, *x as Sol, i as Sol)),
+                DOWN => (move_and_mod_to_range(1, h, *y as Sol, i as Sol), *x),
+                LEFT => (*y, move_and_mod_to_range(1, w, *x as Sol, -(i as Sol))),
+                _ => unreachable!(),
+            };
+            if let FieldType::Valley(n) = &mut storm_state[(s_y, s_x)] {
+                *n |= *storm_mask;
+            }
+        }
+    }
+    (possible_storm_states, cycle_count)
+}
+
+fn move_and_mod_to_range(start: Sol, end: Sol, pos: Sol, n: Sol) -> usize {
+    let p = pos - start;
+    let len = end - start + 1;
+    (start + modulo(p + n, len)) as usize
+}
+
+fn generate_empty_valley(
+    width: &usize,
+    height: &usize,
+    start_x: &usize,
+    end_x: &usize,
+) -> Grid<FieldType> {
+    Grid::from_fn((*height, *width), |(y, x)| {
+        let wall = x == 0
+            || x == width - 1
+            || (y == 0 && x != *start_x)
+            || (y == height - 1 && x != *end_x);
+        match wall {
+            true => FieldType::Rock,
+            false => FieldType::Valley(0),
+        }
+    })
+}
+
+pub struct Day24;
+
+impl Day for Day24 {
+    const DAY: u8 = 24;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+    const ANIMATED: bool = true;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = Maybe<Sol>;
+    type Answer2 = Maybe<Sol>;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> Maybe<Sol> {
+        Maybe(part_1(input))
+    }
+
+    fn part_2(input: &ParseOutput, _params: &()) -> Maybe<Sol> {
+        Maybe(part_2(input))
+    }
+
+    fn animate(input: &ParseOutput, _params: &(), frames: &mut dyn Frames) {
+        animate(input, frames)
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<Maybe<Sol>> {
+        Some(Maybe(reference::part_1(input)))
+    }
+
+    fn reference_2(input: &ParseOutput, _params: &()) -> Option<Maybe<Sol>> {
+        Some(Maybe(reference::part_2(input)))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), Some(18));
+    }
+
+    #[test]
+    pub fn test_part_2() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_2(&parse_output), Some(54));
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        let error = parse("#.######\n#>>.<^<#").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.expected, "'#'");
+        assert_eq!(
+            parse("#.#\n#.#").unwrap_err().expected,
+            "a row between the walls"
+        );
+        assert_eq!(parse("#.#\n...\n#.#").unwrap_err().column, 1);
+        assert_eq!(parse("#..##\n#...#\n###.#").unwrap_err().column, 3);
+        let error = parse("#.###\n#>#.#\n###.#").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        let error = parse("#.###\n#...#\n#####").unwrap_err();
+        assert_eq!(error.expected, "an opening in the bottom wall");
+        assert!(parse("#.#\n#>#\n#.#").is_ok());
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day24, 0..10, 8);
+    }
+}
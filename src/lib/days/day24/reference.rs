@@ -0,0 +1,72 @@
+use super::{ParseOutput, Point, Sol, DOWN, LEFT, RIGHT, TOP};
+use crate::math::lcm;
+use std::collections::HashSet;
+
+/// The fields with a blizzard on them after `minute` minutes, moving every blizzard on its own.
+fn blizzards((width, height, _, _, storms): &ParseOutput, minute: usize) -> HashSet<Point> {
+    let (w, h) = (width - 2, height - 2);
+    storms
+        .iter()
+        .map(|((y, x), direction)| {
+            let (y, x) = (y - 1, x - 1);
+            let (y, x) = match *direction {
+                TOP => ((y + h - minute % h) % h, x),
+                DOWN => ((y + minute) % h, x),
+                LEFT => (y, (x + w - minute % w) % w),
+                RIGHT => (y, (x + minute) % w),
+                _ => unreachable!(),
+            };
+            (y + 1, x + 1)
+        })
+        .collect()
+}
+
+/// The minute of arriving at `to` when leaving `from` at `minute`, keeping every field that can be
+/// reached in each minute. The blizzards repeat, so a trip that takes longer than there are
+/// fields and blizzard patterns never arrives.
+fn trip(parse_output: &ParseOutput, from: Point, to: Point, minute: usize) -> Option<usize> {
+    let (width, height, start, end, _) = parse_output;
+    let open = |(y, x): Point| {
+        (y, x) == *start
+            || (y, x) == *end
+            || (1..height - 1).contains(&y) && (1..width - 1).contains(&x)
+    };
+    let give_up = minute + width * height * lcm(*width as u64 - 2, *height as u64 - 2) as usize;
+    let mut reached = HashSet::from([from]);
+    let mut minute = minute;
+    while !reached.contains(&to) {
+        minute += 1;
+        if minute > give_up {
+            return None;
+        }
+        let storms = blizzards(parse_output, minute);
+        reached = reached
+            .iter()
+            .flat_map(|(y, x)| {
+                [
+                    (*y, *x),
+                    (y + 1, *x),
+                    (y.wrapping_sub(1), *x),
+                    (*y, x + 1),
+                    (*y, x - 1),
+                ]
+            })
+            .filter(|p| open(*p) && !storms.contains(p))
+            .collect();
+    }
+    Some(minute)
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> Option<Sol> {
+    let (_, _, start, end, _) = parse_output;
+    trip(parse_output, *start, *end, 0).map(|m| m as Sol)
+}
+
+/// There, back to the start for the snacks and there again.
+pub fn part_2(parse_output: &ParseOutput) -> Option<Sol> {
+    let (_, _, start, end, _) = parse_output;
+    trip(parse_output, *start, *end, 0)
+        .and_then(|m| trip(parse_output, *end, *start, m))
+        .and_then(|m| trip(parse_output, *start, *end, m))
+        .map(|m| m as Sol)
+}
@@ -0,0 +1,117 @@
+use crate::day::{Day, NoPart2};
+use crate::parse::{ParseError, Source};
+use crate::random::Rng;
+
+type Sol = i64;
+use snafu::{SnafuError, SNAFU};
+
+pub mod reference;
+pub mod snafu;
+
+pub type ParseOutput = Vec<SNAFU>;
+
+const MAIN_INPUT: &str = include_str!("main_input");
+const TEST_INPUT: &str = include_str!("test_input");
+
+pub fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+    let source = Source::new(file);
+    let numbers: ParseOutput = source
+        .lines()
+        .map(|l| match l {
+            "" => Err(source.error(l, "a SNAFU number")),
+            _ => SNAFU::try_from(l).map_err(|error| match error {
+                SnafuError::Digit(i) => source.error(&l[i..], "a SNAFU digit"),
+                SnafuError::Overflow => source.error(l, "a SNAFU number that fits in 64 bits"),
+            }),
+        })
+        .collect::<Result<_, _>>()?;
+    if numbers.is_empty() {
+        return Err(source.error(source.end(), "a SNAFU number"));
+    }
+    Ok(numbers)
+}
+
+/// `size` fuel requirements between 1 and about 10^13.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let digits = rng.range(1..14) as u32;
+            format!("{}\n", SNAFU::from(rng.range(1..10_i64.pow(digits))))
+        })
+        .collect()
+}
+
+pub fn part_1(parse_output: &ParseOutput) -> String {
+    parse_output.iter().cloned().sum::<SNAFU>().as_string()
+}
+
+pub struct Day25;
+
+impl Day for Day25 {
+    const DAY: u8 = 25;
+    const MAIN_INPUT: &'static str = MAIN_INPUT;
+    const TEST_INPUT: &'static str = TEST_INPUT;
+
+    type Input = ParseOutput;
+    type Params = ();
+    type Answer1 = String;
+    type Answer2 = NoPart2;
+
+    fn parse(file: &str) -> Result<ParseOutput, ParseError> {
+        parse(file)
+    }
+
+    fn part_1(input: &ParseOutput, _params: &()) -> String {
+        part_1(input)
+    }
+
+    fn part_2(_input: &ParseOutput, _params: &()) -> NoPart2 {
+        NoPart2
+    }
+
+    fn reference_1(input: &ParseOutput, _params: &()) -> Option<String> {
+        Some(reference::part_1(input))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate(rng, size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_part_1() {
+        let parse_output = parse(TEST_INPUT).unwrap();
+        assert_eq!(part_1(&parse_output), "2=-1=0");
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        assert_eq!(parse("").unwrap_err().expected, "a SNAFU number");
+        assert_eq!(parse("1=\n\n2").unwrap_err().line, 2);
+        assert_eq!(parse("1=\n2x").unwrap_err().column, 2);
+        assert_eq!(part_1(&parse("1\n-").unwrap()), "0");
+        assert_eq!(part_1(&parse("-22").unwrap()), "-22");
+        let error = parse(&"1".repeat(30)).unwrap_err();
+        assert_eq!(error.expected, "a SNAFU number that fits in 64 bits");
+        let largest = "1".repeat(27);
+        assert_eq!(part_1(&parse(&largest).unwrap()), largest);
+        let huge = format!("1{}", "0".repeat(27));
+        assert_eq!(part_1(&parse(&huge).unwrap()), huge);
+    }
+
+    #[test]
+    pub fn test_from_decimal() {
+        assert_eq!(SNAFU::from(2022).to_string(), "1=11-2");
+        assert_eq!(SNAFU::from(314159265).to_string(), "1121-1110-1=0");
+        assert_eq!(Sol::from(SNAFU::from(-8)), -8);
+    }
+
+    #[test]
+    pub fn test_reference() {
+        crate::differential::assert_agrees(&Day25, 0..20, 10);
+    }
+}
@@ -0,0 +1,23 @@
+use super::{ParseOutput, Sol};
+
+/// Adds the numbers in base 10 and writes the sum digit by digit, from the lowest one up. A digit
+/// of 3 or 4 is written as -2 or -1 and carries one into the next place.
+pub fn part_1(numbers: &ParseOutput) -> String {
+    let mut sum: Sol = numbers.iter().cloned().map(Sol::from).sum();
+    let mut digits = Vec::new();
+    while sum > 0 {
+        let (digit, carry) = match sum % 5 {
+            0 => ('0', 0),
+            1 => ('1', 0),
+            2 => ('2', 0),
+            3 => ('=', 1),
+            _ => ('-', 1),
+        };
+        digits.push(digit);
+        sum = sum / 5 + carry;
+    }
+    if digits.is_empty() {
+        digits.push('0');
+    }
+    digits.iter().rev().collect()
+}
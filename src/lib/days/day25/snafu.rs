@@ -1,9 +1,6 @@
-use crate::Sol;
-use std::cmp::Ordering;
+use super::Sol;
 use std::fmt::{Display, Formatter};
 use std::iter::Sum;
-use std::ops::Add;
-use std::vec::Splice;
 
 const SNAFU_BASE: Sol = 5;
 
@@ -21,16 +18,18 @@ impl SNAFU {
         self.base_10 += other;
     }
 
+    /// Works in `i128`, as the largest numbers need a digit whose place value is beyond `Sol`.
     pub fn as_string(&self) -> String {
         let max_n_pow = get_pow(self.base_10, SNAFU_BASE);
         let mut vec = Vec::<char>::new();
 
-        let mut pow = if self.base_10 > SNAFU_BASE.pow(max_n_pow + 1) as Sol / 2 {
+        let base = SNAFU_BASE as i128;
+        let mut pow = if self.base_10.unsigned_abs() as i128 > base.pow(max_n_pow + 1) / 2 {
             max_n_pow + 1
         } else {
             max_n_pow
         };
-        let mut rest = self.base_10;
+        let mut rest = self.base_10 as i128;
         loop {
             if let Some(s) = match rest {
                 -2 => Some('='),
@@ -51,9 +50,8 @@ impl SNAFU {
             if pow == 0 {
                 break;
             }
-            let c_pow_u_1 = SNAFU_BASE.pow(pow) as Sol;
+            let c_pow_u_1 = base.pow(pow);
             let c_pow_u_2 = c_pow_u_1 * 2;
-            let c_pow_n_1 = SNAFU_BASE.pow(pow - 1) as Sol;
 
             if rest <= 0 {
                 if rest.abs() <= c_pow_u_1 / 2 {
@@ -82,26 +80,42 @@ impl SNAFU {
 
 impl From<Sol> for SNAFU {
     fn from(value: Sol) -> Self {
-        todo!()
+        SNAFU::new(value)
     }
 }
 
-impl From<&str> for SNAFU {
-    fn from(s: &str) -> Self {
-        let mut base_10 = 0;
-        for (i, c) in s.chars().enumerate() {
-            let pos = (s.len() - 1 - i) as u32;
-            let num_at_pos = SNAFU_BASE.pow(pos);
-            base_10 += match c {
-                '2' => num_at_pos * 2,
-                '1' => num_at_pos,
+/// Why a string is not a SNAFU number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnafuError {
+    /// The byte index of the first character that is not a SNAFU digit.
+    Digit(usize),
+    /// The number is too large for a `Sol`.
+    Overflow,
+}
+
+impl TryFrom<&str> for SNAFU {
+    type Error = SnafuError;
+
+    fn try_from(s: &str) -> Result<Self, SnafuError> {
+        if s.is_empty() {
+            return Err(SnafuError::Digit(0));
+        }
+        let mut base_10: Sol = 0;
+        for (i, c) in s.char_indices() {
+            let digit = match c {
+                '2' => 2,
+                '1' => 1,
                 '0' => 0,
-                '-' => -num_at_pos,
-                '=' => -num_at_pos * 2,
-                n => unreachable!("{}", n),
-            }
+                '-' => -1,
+                '=' => -2,
+                _ => return Err(SnafuError::Digit(i)),
+            };
+            base_10 = base_10
+                .checked_mul(SNAFU_BASE)
+                .and_then(|n| n.checked_add(digit))
+                .ok_or(SnafuError::Overflow)?;
         }
-        SNAFU::new(base_10)
+        Ok(SNAFU::new(base_10))
     }
 }
 
@@ -127,11 +141,11 @@ impl Sum for SNAFU {
 }
 
 fn get_pow(num: Sol, base: Sol) -> u32 {
-    let mut n = num;
-    let mut c = 0;
+    let mut n = num.unsigned_abs();
+    let mut c: u32 = 0;
     while n > 0 {
-        n /= base;
+        n /= base as u64;
         c += 1;
     }
-    c - 1
+    c.saturating_sub(1)
 }
@@ -0,0 +1,109 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::runner::Solver;
+
+pub static DAYS: [&dyn Solver; 24] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+pub fn get(day: u8) -> Option<&'static dyn Solver> {
+    DAYS.iter().find(|s| s.day() == day).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day::Day;
+    use crate::random::Rng;
+
+    fn check_generate<D: Day>() {
+        for (seed, size) in [(0, 1), (1, 5), (2, 30)] {
+            let file = D::generate(&mut Rng::new(seed), size);
+            assert_eq!(file, D::generate(&mut Rng::new(seed), size));
+            if let Err(e) = D::parse(&file) {
+                panic!(
+                    "day {} seed {} size {}: {}\n{}",
+                    D::DAY,
+                    seed,
+                    size,
+                    e,
+                    file
+                );
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_generate() {
+        check_generate::<day01::Day01>();
+        check_generate::<day02::Day02>();
+        check_generate::<day03::Day03>();
+        check_generate::<day04::Day04>();
+        check_generate::<day05::Day05>();
+        check_generate::<day06::Day06>();
+        check_generate::<day07::Day07>();
+        check_generate::<day08::Day08>();
+        check_generate::<day09::Day09>();
+        check_generate::<day10::Day10>();
+        check_generate::<day11::Day11>();
+        check_generate::<day12::Day12>();
+        check_generate::<day13::Day13>();
+        check_generate::<day14::Day14>();
+        check_generate::<day15::Day15>();
+        check_generate::<day16::Day16>();
+        check_generate::<day18::Day18>();
+        check_generate::<day19::Day19>();
+        check_generate::<day20::Day20>();
+        check_generate::<day21::Day21>();
+        check_generate::<day22::Day22>();
+        check_generate::<day23::Day23>();
+        check_generate::<day24::Day24>();
+        check_generate::<day25::Day25>();
+    }
+}
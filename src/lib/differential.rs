@@ -0,0 +1,174 @@
+use crate::day::{Day, GENERATED};
+use crate::parse::ParseError;
+use crate::runner::Solver;
+use std::fmt::{Display, Formatter};
+use std::ops::Range;
+
+/// The answer to a part next to the one of its reference solver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comparison {
+    pub part: u8,
+    pub answer: String,
+    pub reference: String,
+}
+
+impl Comparison {
+    pub fn agrees(&self) -> bool {
+        self.answer == self.reference
+    }
+}
+
+/// A generated input on which a solver and its reference disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub day: u8,
+    pub seed: u64,
+    pub size: usize,
+    pub comparison: Comparison,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {:02} part {} answers {:?} but the reference answers {:?} (aoc {} --generate {} --seed {} --emit)",
+            self.day,
+            self.comparison.part,
+            self.comparison.answer,
+            self.comparison.reference,
+            self.day,
+            self.size,
+            self.seed
+        )
+    }
+}
+
+/// Solves `file` with both the solvers and the reference solvers of `D`, for the parts that
+/// have a reference.
+pub fn compare<D: Day>(file: &str, input_name: &str) -> Result<Vec<Comparison>, ParseError> {
+    let params = D::params(input_name);
+    let input = D::parse(file)?;
+    let mut comparisons = Vec::new();
+    if let Some(reference) = D::reference_1(&input, &params) {
+        comparisons.push(Comparison {
+            part: 1,
+            answer: D::part_1(&input, &params).to_string(),
+            reference: reference.to_string(),
+        });
+    }
+    if let Some(reference) = D::has_part_2()
+        .then(|| D::reference_2(&input, &params))
+        .flatten()
+    {
+        comparisons.push(Comparison {
+            part: 2,
+            answer: D::part_2(&input, &params).to_string(),
+            reference: reference.to_string(),
+        });
+    }
+    Ok(comparisons)
+}
+
+/// Runs `solver` and its references on the inputs generated from every seed in `seeds`, stopping
+/// at the first answer they disagree on.
+pub fn first_disagreement(
+    solver: &dyn Solver,
+    seeds: Range<u64>,
+    size: usize,
+) -> Result<Option<Disagreement>, ParseError> {
+    for seed in seeds {
+        let file = solver.generate(seed, size);
+        let comparisons = solver.compare(&file, GENERATED)?;
+        if let Some(comparison) = comparisons.into_iter().find(|c| !c.agrees()) {
+            return Ok(Some(Disagreement {
+                day: solver.day(),
+                seed,
+                size,
+                comparison,
+            }));
+        }
+    }
+    Ok(None)
+}
+
+/// Panics with the first disagreement of `solver` and its references, for the tests of the days.
+#[cfg(test)]
+pub fn assert_agrees(solver: &dyn Solver, seeds: Range<u64>, size: usize) {
+    match first_disagreement(solver, seeds, size) {
+        Ok(None) => {}
+        Ok(Some(disagreement)) => panic!("{}", disagreement),
+        Err(e) => panic!("generated input does not parse: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day::NoPart2;
+    use crate::parse::Source;
+    use crate::random::Rng;
+
+    struct Max;
+
+    impl Day for Max {
+        const DAY: u8 = 1;
+        const MAIN_INPUT: &'static str = "";
+        const TEST_INPUT: &'static str = "";
+
+        type Input = Vec<u32>;
+        type Params = ();
+        type Answer1 = u32;
+        type Answer2 = NoPart2;
+
+        fn parse(file: &str) -> Result<Vec<u32>, ParseError> {
+            let source = Source::new(file);
+            source.lines().map(|l| source.number(l)).collect()
+        }
+
+        fn part_1(input: &Vec<u32>, _params: &()) -> u32 {
+            input.iter().take(3).copied().max().unwrap_or(0)
+        }
+
+        fn part_2(_input: &Vec<u32>, _params: &()) -> NoPart2 {
+            NoPart2
+        }
+
+        fn reference_1(input: &Vec<u32>, _params: &()) -> Option<u32> {
+            Some(input.iter().copied().max().unwrap_or(0))
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> String {
+            (0..size).map(|_| format!("{}\n", rng.below(100))).collect()
+        }
+    }
+
+    #[test]
+    pub fn test_compare() {
+        let comparisons = compare::<Max>("1\n2\n3\n4", GENERATED).unwrap();
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(
+            (
+                comparisons[0].answer.as_str(),
+                comparisons[0].reference.as_str()
+            ),
+            ("3", "4")
+        );
+        assert!(!comparisons[0].agrees());
+        assert!(compare::<Max>("1\nx", GENERATED).is_err());
+    }
+
+    #[test]
+    pub fn test_first_disagreement() {
+        assert_eq!(first_disagreement(&Max, 0..10, 3).unwrap(), None);
+        let disagreement = first_disagreement(&Max, 0..100, 10).unwrap().unwrap();
+        assert_eq!((disagreement.day, disagreement.size), (1, 10));
+        assert_eq!(
+            first_disagreement(&Max, 0..disagreement.seed, 10).unwrap(),
+            None
+        );
+        assert!(disagreement.to_string().ends_with(&format!(
+            "(aoc 1 --generate 10 --seed {} --emit)",
+            disagreement.seed
+        )));
+    }
+}
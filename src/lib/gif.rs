@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+pub type Rgb = [u8; 3];
+
+const MAX_CODE: u16 = 4096;
+
+/// One LZW compressed image of an animation, placed at `left`, `top` on the screen.
+#[derive(Debug, Clone)]
+pub struct Image {
+    pub left: u16,
+    pub top: u16,
+    pub width: u16,
+    pub height: u16,
+    data: Vec<u8>,
+}
+
+impl Image {
+    /// `indices` holds one palette index per pixel, row by row.
+    pub fn new(
+        left: u16,
+        top: u16,
+        width: u16,
+        height: u16,
+        indices: &[u8],
+        palette_bits: u8,
+    ) -> Image {
+        assert_eq!(indices.len(), width as usize * height as usize);
+        Image {
+            left,
+            top,
+            width,
+            height,
+            data: lzw(min_code_size(palette_bits), indices),
+        }
+    }
+}
+
+fn min_code_size(palette_bits: u8) -> u8 {
+    palette_bits.max(2)
+}
+
+fn palette_bits(palette: &[Rgb]) -> u8 {
+    (1..=8)
+        .find(|b| 1 << b >= palette.len())
+        .expect("at most 256 colours")
+}
+
+/// Encodes an endlessly looping animated GIF. Every image is cleared to the background,
+/// palette entry 0, before the next one is drawn.
+pub fn encode(
+    width: u16,
+    height: u16,
+    palette: &[Rgb],
+    delay_cs: u16,
+    images: &[Image],
+) -> Vec<u8> {
+    let bits = palette_bits(palette);
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend(width.to_le_bytes());
+    gif.extend(height.to_le_bytes());
+    gif.extend([0x80 | 0x70 | (bits - 1), 0, 0]);
+    for i in 0..1 << bits {
+        gif.extend(palette.get(i).unwrap_or(&[0, 0, 0]));
+    }
+    gif.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    for image in images {
+        gif.extend([0x21, 0xf9, 0x04, 0x08]);
+        gif.extend(delay_cs.to_le_bytes());
+        gif.extend([0, 0, 0x2c]);
+        for v in [image.left, image.top, image.width, image.height] {
+            gif.extend(v.to_le_bytes());
+        }
+        gif.extend([0, min_code_size(bits)]);
+        for block in image.data.chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend(block);
+        }
+        gif.push(0);
+    }
+    gif.push(0x3b);
+    gif
+}
+
+/// Variable width LZW as used by GIF, starting with a clear code and restarting once the
+/// code table is full.
+pub fn lzw(min_code_size: u8, indices: &[u8]) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+    let mut out = BitWriter::default();
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = end + 1;
+    let mut width = min_code_size + 1;
+
+    out.write(clear, width);
+    let mut prefix: Option<u16> = None;
+    for &k in indices {
+        let p = match prefix {
+            None => {
+                prefix = Some(k as u16);
+                continue;
+            }
+            Some(p) => p,
+        };
+        if let Some(&code) = table.get(&(p, k)) {
+            prefix = Some(code);
+            continue;
+        }
+        out.write(p, width);
+        table.insert((p, k), next);
+        next += 1;
+        if next > 1 << width && width < 12 {
+            width += 1;
+        }
+        if next == MAX_CODE {
+            out.write(clear, width);
+            table.clear();
+            next = end + 1;
+            width = min_code_size + 1;
+        }
+        prefix = Some(k as u16);
+    }
+    if let Some(p) = prefix {
+        out.write(p, width);
+    }
+    out.write(end, width);
+    out.finish()
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u8) {
+        self.buffer |= (code as u32) << self.bits;
+        self.bits += width;
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unlzw(min_code_size: u8, data: &[u8]) -> Vec<u8> {
+        let clear = 1usize << min_code_size;
+        let (mut pos, mut width) = (0, min_code_size + 1);
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut previous: Option<Vec<u8>> = None;
+        let mut out = Vec::new();
+        loop {
+            let mut code = 0;
+            for i in 0..width as usize {
+                code |= ((data[(pos + i) / 8] >> ((pos + i) % 8)) as usize & 1) << i;
+            }
+            pos += width as usize;
+            if code == clear {
+                table = (0..clear).map(|i| vec![i as u8]).collect();
+                table.extend([vec![], vec![]]);
+                width = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == clear + 1 {
+                return out;
+            }
+            let entry = match (table.get(code), &previous) {
+                (Some(e), _) => e.clone(),
+                (None, Some(p)) => [p.clone(), vec![p[0]]].concat(),
+                (None, None) => panic!("unknown first code"),
+            };
+            if let Some(p) = previous {
+                if table.len() < 4096 {
+                    table.push([p, vec![entry[0]]].concat());
+                }
+            }
+            if table.len() == 1 << width && width < 12 {
+                width += 1;
+            }
+            out.extend(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    pub fn test_lzw() {
+        let pixels: Vec<u8> = (0..20_000u32)
+            .map(|i| ((i * i / 7 + i / 13) % 8) as u8)
+            .collect();
+        assert_eq!(unlzw(3, &lzw(3, &pixels)), pixels);
+        let flat = vec![1; 5000];
+        assert_eq!(unlzw(2, &lzw(2, &flat)), flat);
+        assert_eq!(unlzw(2, &lzw(2, &[])), []);
+    }
+
+    #[test]
+    pub fn test_encode() {
+        let palette = [[0, 0, 0], [255, 255, 255], [255, 0, 0]];
+        let image = Image::new(1, 2, 2, 2, &[0, 1, 2, 1], palette_bits(&palette));
+        let gif = encode(4, 5, &palette, 10, &[image.clone(), image]);
+        assert!(gif.starts_with(b"GIF89a\x04\x00\x05\x00\xf1"));
+        assert_eq!(&gif[13..25], [0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0, 0]);
+        assert_eq!(gif.iter().filter(|b| **b == 0x2c).count(), 2);
+        assert_eq!(gif.last(), Some(&0x3b));
+    }
+}
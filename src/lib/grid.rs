@@ -0,0 +1,270 @@
+use crate::math::modulo;
+use crate::parse::{ParseError, Source};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A `(y, x)` position, rows first like everywhere else in the days.
+pub type Pos = (usize, usize);
+
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Rectangular grid stored row by row in one `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    height: usize,
+    width: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new((height, width): (usize, usize), value: T) -> Grid<T> {
+        Grid {
+            cells: vec![value; height * width],
+            height,
+            width,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn<F: FnMut(Pos) -> T>((height, width): (usize, usize), mut f: F) -> Grid<T> {
+        Grid {
+            cells: (0..height * width)
+                .map(|i| f((i / width, i % width)))
+                .collect(),
+            height,
+            width,
+        }
+    }
+
+    /// Reads one cell per character of every line in `text`, a slice of `source`. `cell` gets
+    /// the position and character and returns `None` for characters that are not allowed.
+    pub fn parse<F>(
+        source: &Source,
+        text: &str,
+        expected: &str,
+        mut cell: F,
+    ) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(Pos, char) -> Option<T>,
+    {
+        let width = text.lines().next().map_or(0, |l| l.chars().count());
+        let mut cells = Vec::new();
+        let mut height = 0;
+        for (y, l) in text.lines().enumerate() {
+            if l.chars().count() != width {
+                return Err(source.error(l, format!("a row of {} fields", width)));
+            }
+            for (x, (i, c)) in l.char_indices().enumerate() {
+                cells.push(cell((y, x), c).ok_or_else(|| source.error(&l[i..], expected))?);
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            cells,
+            height,
+            width,
+        })
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, (y, x): Pos) -> bool {
+        y < self.height && x < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        let width = self.width;
+        match self.contains(pos) {
+            true => Some(&mut self.cells[pos.0 * width + pos.1]),
+            false => None,
+        }
+    }
+
+    /// Bounds-checked access for coordinates that may be negative.
+    pub fn get_signed(&self, y: isize, x: isize) -> Option<&T> {
+        if y < 0 || x < 0 {
+            return None;
+        }
+        self.get((y as usize, x as usize))
+    }
+
+    /// Access that wraps around the edges, as if the grid repeated in every direction.
+    pub fn get_wrapping(&self, y: isize, x: isize) -> &T {
+        &self[(
+            modulo(y, self.height as isize) as usize,
+            modulo(x, self.width as isize) as usize,
+        )]
+    }
+
+    /// The position `(dy, dx)` away from `pos`, if it is inside the grid.
+    pub fn offset(&self, (y, x): Pos, (dy, dx): (isize, isize)) -> Option<Pos> {
+        let pos = (y.checked_add_signed(dy)?, x.checked_add_signed(dx)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Positions above, left, right and below `pos` that are inside the grid.
+    pub fn neighbours_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |d| self.offset(pos, *d))
+    }
+
+    /// Positions of the eight surrounding cells that are inside the grid, in reading order.
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |d| self.offset(pos, *d))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {} is outside the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height * width).map(move |i| (i / width, i % width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", pos))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(file: &str) -> Result<Grid<u32>, ParseError> {
+        let source = Source::new(file);
+        Grid::parse(&source, file, "a digit", |_, c| c.to_digit(10))
+    }
+
+    #[test]
+    pub fn test_parse() {
+        let grid = digits("123\n456\n").unwrap();
+        assert_eq!(grid.size(), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let error = digits("123\n4x6").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = digits("123\n45").unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (2, "a row of 3 fields")
+        );
+        assert_eq!(digits("").unwrap().size(), (0, 0));
+    }
+
+    #[test]
+    pub fn test_access() {
+        let mut grid = Grid::from_fn((3, 4), |(y, x)| y * 10 + x);
+        assert_eq!(grid.get((2, 3)), Some(&23));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert_eq!(grid.get_signed(1, 2), Some(&12));
+        assert_eq!(grid.get_wrapping(-1, 5), &21);
+        assert_eq!(grid.offset((0, 0), (0, -1)), None);
+        assert_eq!(grid.offset((0, 0), (2, 3)), Some((2, 3)));
+
+        grid[(0, 1)] = 99;
+        *grid.get_mut((2, 0)).unwrap() = 77;
+        assert_eq!(grid.row(0), [0, 99, 2, 3]);
+        assert_eq!(grid.column(0).collect::<Vec<_>>(), [&0, &10, &77]);
+        assert_eq!(grid.column(3).next_back(), Some(&23));
+        grid.row_mut(1).fill(5);
+        assert_eq!(grid.map(|c| c % 2).row(1), [1, 1, 1, 1]);
+        assert_eq!(Grid::new((2, 2), 'a').to_string(), "aa\naa\n");
+    }
+
+    #[test]
+    pub fn test_neighbours() {
+        let grid = Grid::new((3, 3), 0);
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours_4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours_8((0, 2)).collect::<Vec<_>>(),
+            [(0, 1), (1, 1), (1, 2)]
+        );
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 8);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &0)));
+    }
+}
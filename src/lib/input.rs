@@ -0,0 +1,66 @@
+use std::io::{BufRead, BufReader, Read};
+use std::{fs, io};
+
+pub const STDIN: &str = "-";
+
+/// Reads a puzzle input from `path`, or from stdin if `path` is `-`.
+pub fn read(path: &str) -> io::Result<String> {
+    if path == STDIN {
+        let mut file = String::new();
+        io::stdin().read_to_string(&mut file)?;
+        return Ok(file);
+    }
+
+    fs::read_to_string(path).map_err(|e| with_path(path, e))
+}
+
+/// Opens a puzzle input at `path`, or stdin if `path` is `-`, to be read line by line instead of
+/// all at once.
+pub fn open(path: &str) -> io::Result<Box<dyn BufRead>> {
+    if path == STDIN {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+
+    let file = fs::File::open(path).map_err(|e| with_path(path, e))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+fn with_path(path: &str, e: io::Error) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {}", path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    pub fn test_read_file() {
+        let path = env::temp_dir().join(format!("aoc2022_input_{}", std::process::id()));
+        fs::write(&path, "1\n2\n").unwrap();
+        assert_eq!(read(path.to_str().unwrap()).unwrap(), "1\n2\n");
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    pub fn test_read_missing_file() {
+        let error = read("does/not/exist").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(error.to_string().starts_with("does/not/exist: "));
+        let error = open("does/not/exist").err().unwrap();
+        assert!(error.to_string().starts_with("does/not/exist: "));
+    }
+
+    #[test]
+    pub fn test_open_file() {
+        let path = env::temp_dir().join(format!("aoc2022_open_{}", std::process::id()));
+        fs::write(&path, "1\n2\n").unwrap();
+        let lines: Vec<String> = open(path.to_str().unwrap())
+            .unwrap()
+            .lines()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(lines, ["1", "2"]);
+        fs::remove_file(path).unwrap();
+    }
+}
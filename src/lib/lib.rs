@@ -0,0 +1,21 @@
+pub mod animation;
+pub mod answers;
+pub mod bench;
+pub mod circle;
+pub mod combinator;
+pub mod cycle;
+pub mod day;
+pub mod days;
+pub mod differential;
+pub mod gif;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod math;
+pub mod parse;
+pub mod point;
+pub mod pool;
+pub mod random;
+pub mod recorder;
+pub mod runner;
+pub mod search;
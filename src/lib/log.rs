@@ -0,0 +1,184 @@
+use std::fmt::{Arguments, Display, Formatter};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::{Duration, Instant};
+
+/// How often `Progress` reports while a search is running.
+pub const PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Off as u8);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub const NAMES: [&'static str; 4] = ["off", "info", "debug", "trace"];
+
+    pub fn named(name: &str) -> Option<Level> {
+        [Level::Off, Level::Info, Level::Debug, Level::Trace]
+            .into_iter()
+            .find(|l| l.to_string() == name)
+    }
+
+    /// The level of `-v` repeated `n` times.
+    pub fn verbosity(n: usize) -> Level {
+        match n {
+            0 => Level::Off,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", Level::NAMES[*self as usize])
+    }
+}
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    Level::verbosity(LEVEL.load(Ordering::Relaxed) as usize)
+}
+
+pub fn enabled(level: Level) -> bool {
+    level != Level::Off && level <= self::level()
+}
+
+/// Writes to stderr so that stdout only carries answers. Use the `info!`, `debug!` and
+/// `trace!` macros, which skip formatting when their level is disabled.
+pub fn log(level: Level, args: Arguments) {
+    if enabled(level) {
+        eprintln!("[{}] {}", level, args);
+    }
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Info) {
+            $crate::log::log($crate::log::Level::Info, format_args!($($arg)*))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            $crate::log::log($crate::log::Level::Debug, format_args!($($arg)*))
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            $crate::log::log($crate::log::Level::Trace, format_args!($($arg)*))
+        }
+    };
+}
+
+/// Counts the states of a long search and reports them, their rate and the best value found
+/// so far at info level every `PROGRESS_INTERVAL`.
+pub struct Progress {
+    label: String,
+    enabled: bool,
+    states: u64,
+    best: Option<String>,
+    start: Instant,
+    last_report: Instant,
+}
+
+impl Progress {
+    pub fn new(label: &str) -> Progress {
+        let now = Instant::now();
+        Progress {
+            label: label.to_string(),
+            enabled: enabled(Level::Info),
+            states: 0,
+            best: None,
+            start: now,
+            last_report: now,
+        }
+    }
+
+    pub fn states(&self) -> u64 {
+        self.states
+    }
+
+    /// Counts one explored state.
+    pub fn tick(&mut self) {
+        self.states += 1;
+        if self.enabled
+            && self.states.is_multiple_of(4096)
+            && self.last_report.elapsed() >= PROGRESS_INTERVAL
+        {
+            self.last_report = Instant::now();
+            log(Level::Info, format_args!("{}", self.report()));
+        }
+    }
+
+    pub fn improve<T: Display>(&mut self, best: T) {
+        if self.enabled {
+            self.best = Some(best.to_string());
+        }
+    }
+
+    pub fn report(&self) -> String {
+        let elapsed = self.start.elapsed().as_secs_f64().max(f64::MIN_POSITIVE);
+        format!(
+            "{}: {} states, {:.0} states/s, best {}",
+            self.label,
+            self.states,
+            self.states as f64 / elapsed,
+            self.best.as_deref().unwrap_or("-")
+        )
+    }
+
+    /// Reports the final numbers at debug level.
+    pub fn finish(self) {
+        crate::debug!("{} done after {:.1?}", self.report(), self.start.elapsed());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_level() {
+        assert_eq!(Level::named("debug"), Some(Level::Debug));
+        assert_eq!(Level::named("loud"), None);
+        assert_eq!(Level::verbosity(0), Level::Off);
+        assert_eq!(Level::verbosity(5), Level::Trace);
+        assert!(Level::Info < Level::Trace);
+        assert!(!enabled(Level::Off));
+    }
+
+    #[test]
+    pub fn test_progress() {
+        let mut progress = Progress {
+            enabled: true,
+            ..Progress::new("search")
+        };
+        for _ in 0..10 {
+            progress.tick();
+        }
+        progress.improve(42);
+        assert_eq!(progress.states(), 10);
+        let report = progress.report();
+        assert!(report.starts_with("search: 10 states, "));
+        assert!(report.ends_with(" states/s, best 42"));
+        assert!(Progress::new("idle").report().ends_with("best -"));
+    }
+}
@@ -0,0 +1,198 @@
+use std::ops::{Add, Rem};
+
+/// `s` modulo `o`, with the sign of `o`. Only adds `o` to a remainder of the other sign, so it
+/// does not overflow for any `o`.
+pub fn modulo<T>(s: T, o: T) -> T
+where
+    T: Copy + Default + PartialOrd + Add<Output = T> + Rem<Output = T>,
+{
+    let zero = T::default();
+    let r = s % o;
+    if (r < zero && o > zero) || (r > zero && o < zero) {
+        r + o
+    } else {
+        r
+    }
+}
+
+pub fn manhattan_distance(p1: (usize, usize), p2: (usize, usize)) -> u32 {
+    (p1.0.abs_diff(p2.0) + p1.1.abs_diff(p2.1)) as u32
+}
+
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub fn checked_lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Panics if the result doesn't fit into a `u64`.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    checked_lcm(a, b).unwrap_or_else(|| panic!("lcm of {} and {} overflows u64", a, b))
+}
+
+/// 0 for no numbers, which divides nothing but is divided by everything.
+pub fn gcd_all<I: IntoIterator<Item = u64>>(numbers: I) -> u64 {
+    numbers.into_iter().fold(0, gcd)
+}
+
+/// 1 for no numbers. Panics if the result doesn't fit into a `u64`.
+pub fn lcm_all<I: IntoIterator<Item = u64>>(numbers: I) -> u64 {
+    numbers.into_iter().fold(1, lcm)
+}
+
+pub fn checked_product<I: IntoIterator<Item = u64>>(numbers: I) -> Option<u64> {
+    numbers
+        .into_iter()
+        .try_fold(1u64, |acc, n| acc.checked_mul(n))
+}
+
+/// Panics if the result doesn't fit into a `u64`.
+pub fn product<I: IntoIterator<Item = u64>>(numbers: I) -> u64 {
+    checked_product(numbers).expect("product overflows u64")
+}
+
+/// `(g, x, y)` with `g = gcd(a, b) = a * x + b * y`. Works in `i128`, as `gcd(i64::MIN, 0)`
+/// does not fit into an `i64`.
+pub fn extended_gcd(a: i64, b: i64) -> (i128, i128, i128) {
+    fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+        if b == 0 {
+            (a.abs(), a.signum(), 0)
+        } else {
+            let (g, x, y) = extended_gcd(b, a % b);
+            (g, y, x - (a / b) * y)
+        }
+    }
+    extended_gcd(a as i128, b as i128)
+}
+
+/// The `x` in `0..m` with `a * x = 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(modulo(a, m), m);
+    (g == 1).then(|| modulo(x, m as i128) as i64)
+}
+
+/// `base` to the power of `exp`, modulo `m`.
+pub fn mod_pow(base: u64, mut exp: u64, m: u64) -> u64 {
+    let m = m as u128;
+    let mut base = base as u128 % m;
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Solves `x = r (mod m)` for all `(r, m)` pairs, which don't need to be coprime. Returns the
+/// smallest non-negative `x` with the lcm of the moduli, or `None` if there is no solution or a
+/// modulus is not positive. Panics if the lcm doesn't fit into an `i64`.
+pub fn crt<I: IntoIterator<Item = (i64, i64)>>(congruences: I) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0i64, 1i64), |(r1, m1), (r2, m2)| {
+            if m2 <= 0 {
+                return None;
+            }
+            let (g, p, _) = extended_gcd(m1, m2);
+            let step_modulus = m2 as i128 / g;
+            let difference = r2 as i128 - r1 as i128;
+            if difference % g != 0 {
+                return None;
+            }
+            let m = m1 as i128 * step_modulus;
+            let m = i64::try_from(m)
+                .unwrap_or_else(|_| panic!("lcm of {} and {} overflows i64", m1, m2));
+            let step =
+                modulo(difference / g, step_modulus) * modulo(p, step_modulus) % step_modulus;
+            let x = modulo(r1 as i128 + m1 as i128 * step, m as i128);
+            Some((x as i64, m))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_modulo() {
+        assert_eq!(modulo(7, 4), 3);
+        assert_eq!(modulo(-1, 4), 3);
+        assert_eq!(modulo(-8, 4), 0);
+        assert_eq!(modulo(-9_i64, 5), 1);
+        assert_eq!(modulo(7, -4), -1);
+        assert_eq!(modulo(-1, i64::MAX), i64::MAX - 1);
+        assert_eq!(modulo(i64::MIN, i64::MAX), i64::MAX - 1);
+        assert_eq!(modulo(5, i64::MIN), i64::MIN + 5);
+        assert_eq!(modulo(u8::MAX - 1, 200), 54);
+    }
+
+    #[test]
+    pub fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(lcm_all([23, 19, 13, 17]), 96577);
+        assert_eq!(lcm_all([]), 1);
+        assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64")]
+    pub fn test_lcm_overflow() {
+        lcm_all([u64::MAX, 2]);
+    }
+
+    #[test]
+    pub fn test_product() {
+        assert_eq!(product([2, 3, 7]), 42);
+        assert_eq!(checked_product([]), Some(1));
+        assert_eq!(checked_product([u64::MAX, 2]), None);
+    }
+
+    #[test]
+    pub fn test_mod_inverse() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(i64::MIN, 0), (1 << 63, -1, 0));
+        assert_eq!(extended_gcd(i64::MIN, -1), (1, 0, -1));
+        assert_eq!(mod_inverse(i64::MAX - 1, i64::MAX), Some(i64::MAX - 1));
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+        assert_eq!(mod_pow(4, 13, 497), 445);
+        assert_eq!(mod_pow(u64::MAX, 2, u64::MAX - 1), 1);
+        assert_eq!(mod_pow(5, 0, 1), 0);
+    }
+
+    #[test]
+    pub fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(1, 4), (0, 0)]), None);
+        assert_eq!(crt([(1, -4)]), None);
+        assert_eq!(crt([(i64::MIN, 3), (i64::MAX, 5)]), Some((7, 15)));
+    }
+
+    #[test]
+    pub fn test_manhattan_distance() {
+        assert_eq!(manhattan_distance((0, 0), (0, 0)), 0);
+        assert_eq!(manhattan_distance((1, 5), (4, 2)), 6);
+        assert_eq!(manhattan_distance((4, 2), (1, 5)), 6);
+    }
+}
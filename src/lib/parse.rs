@@ -0,0 +1,176 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Wraps a puzzle input so errors about any slice of it can report where that slice starts.
+#[derive(Debug, Clone, Copy)]
+pub struct Source<'a> {
+    file: &'a str,
+}
+
+impl<'a> Source<'a> {
+    pub fn new(file: &'a str) -> Source<'a> {
+        Source { file }
+    }
+
+    pub fn file(&self) -> &'a str {
+        self.file
+    }
+
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.file.lines()
+    }
+
+    /// Splits the input into blocks separated by blank lines, with `\n` or `\r\n` line endings.
+    pub fn sections(&self) -> Vec<&'a str> {
+        let mut sections = Vec::new();
+        let mut start = None;
+        let mut end = 0;
+        let mut offset = 0;
+        for line in self.file.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\r', '\n']);
+            if content.trim().is_empty() {
+                if let Some(s) = start.take() {
+                    sections.push(&self.file[s..end]);
+                }
+            } else {
+                start.get_or_insert(offset);
+                end = offset + content.len();
+            }
+            offset += line.len();
+        }
+        if let Some(s) = start {
+            sections.push(&self.file[s..end]);
+        }
+        sections
+    }
+
+    /// The empty slice at the very end of the input, for reporting missing content.
+    pub fn end(&self) -> &'a str {
+        &self.file[self.file.len()..]
+    }
+
+    /// Builds an error located at `at`, which has to be a slice of this input.
+    pub fn error(&self, at: &str, expected: impl Into<String>) -> ParseError {
+        let file_start = self.file.as_ptr() as usize;
+        let offset = (at.as_ptr() as usize)
+            .saturating_sub(file_start)
+            .min(self.file.len());
+        let before = &self.file[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let found = at.lines().next().unwrap_or("");
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found: if found.is_empty() {
+                "end of line".into()
+            } else {
+                format!("'{}'", found)
+            },
+        }
+    }
+
+    pub fn number<T: FromStr>(&self, s: &'a str) -> Result<T, ParseError> {
+        s.parse().map_err(|_| self.error(s, "a number"))
+    }
+
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error(s, format!("'{}'", delimiter)))
+    }
+
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format!("'{}'", prefix)))
+    }
+
+    pub fn strip_suffix(&self, s: &'a str, suffix: &str) -> Result<&'a str, ParseError> {
+        s.strip_suffix(suffix).ok_or_else(|| {
+            let cut = s.len().saturating_sub(suffix.len());
+            let at = &s[(0..=cut)
+                .rev()
+                .find(|i| s.is_char_boundary(*i))
+                .unwrap_or(0)..];
+            self.error(at, format!("'{}'", suffix))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_error_position() {
+        let file = "1000\n2000\n\n3x00\n";
+        let source = Source::new(file);
+        let line = source.lines().nth(3).unwrap();
+        let error = source.error(&line[1..], "a digit");
+        assert_eq!((error.line, error.column), (4, 2));
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 2: expected a digit, found 'x00'"
+        );
+    }
+
+    #[test]
+    pub fn test_error_at_end() {
+        let source = Source::new("a\nb");
+        let error = source.error(source.end(), "a blank line");
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.found, "end of line");
+    }
+
+    #[test]
+    pub fn test_sections() {
+        let source = Source::new("1\n2\n\n3\n\n\n4\n");
+        assert_eq!(source.sections(), ["1\n2", "3", "4"]);
+        let source = Source::new("1\r\n2\r\n\r\n3\r\n");
+        assert_eq!(source.sections(), ["1\r\n2", "3"]);
+        assert!(Source::new("\n\n").sections().is_empty());
+    }
+
+    #[test]
+    pub fn test_helpers() {
+        let file = "move 1 from 2";
+        let source = Source::new(file);
+        assert_eq!(source.number::<u32>(&file[5..6]), Ok(1));
+        assert_eq!(source.number::<u32>(&file[7..11]).unwrap_err().column, 8);
+        assert_eq!(source.split_once(file, " from "), Ok(("move 1", "2")));
+        assert_eq!(source.strip_prefix(file, "move "), Ok("1 from 2"));
+        let error = source.strip_prefix(file, "Move ").unwrap_err();
+        assert_eq!(error.expected, "'Move '");
+        assert_eq!(source.strip_suffix(file, "2"), Ok("move 1 from "));
+        assert_eq!(source.strip_suffix(file, "3").unwrap_err().column, 13);
+        let file = "1 from ü";
+        let source = Source::new(file);
+        assert_eq!(source.strip_suffix(file, "2").unwrap_err().column, 8);
+        assert_eq!(source.strip_suffix(file, " to 1").unwrap_err().column, 5);
+    }
+}
@@ -0,0 +1,299 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A position or offset on a plane, `y` growing downwards like the rows of the puzzle inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2 {
+    pub y: i32,
+    pub x: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub z: i32,
+    pub y: i32,
+    pub x: i32,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2::new(0, 0);
+
+    pub const fn new(y: i32, x: i32) -> Point2 {
+        Point2 { y, x }
+    }
+
+    pub fn manhattan(self, other: Point2) -> i32 {
+        (self.y - other.y).abs() + (self.x - other.x).abs()
+    }
+
+    pub fn chebyshev(self, other: Point2) -> i32 {
+        (self.y - other.y).abs().max((self.x - other.x).abs())
+    }
+
+    /// The offset of at most one step in each axis that points the same way.
+    pub fn signum(self) -> Point2 {
+        Point2::new(self.y.signum(), self.x.signum())
+    }
+
+    pub fn min(self, other: Point2) -> Point2 {
+        Point2::new(self.y.min(other.y), self.x.min(other.x))
+    }
+
+    pub fn max(self, other: Point2) -> Point2 {
+        Point2::new(self.y.max(other.y), self.x.max(other.x))
+    }
+
+    /// The points above, right, below and left of this one.
+    pub fn neighbours_4(self) -> [Point2; 4] {
+        Direction::ALL.map(|d| self + d.offset())
+    }
+
+    /// The eight surrounding points in reading order.
+    pub fn neighbours_8(self) -> [Point2; 8] {
+        [
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -1),
+            (0, 1),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+        ]
+        .map(|(y, x)| self + Point2::new(y, x))
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub const fn new(z: i32, y: i32, x: i32) -> Point3 {
+        Point3 { z, y, x }
+    }
+
+    pub fn manhattan(self, other: Point3) -> i32 {
+        (self.z - other.z).abs() + (self.y - other.y).abs() + (self.x - other.x).abs()
+    }
+
+    pub fn chebyshev(self, other: Point3) -> i32 {
+        (self.z - other.z)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.x - other.x).abs())
+    }
+
+    pub fn min(self, other: Point3) -> Point3 {
+        Point3::new(
+            self.z.min(other.z),
+            self.y.min(other.y),
+            self.x.min(other.x),
+        )
+    }
+
+    pub fn max(self, other: Point3) -> Point3 {
+        Point3::new(
+            self.z.max(other.z),
+            self.y.max(other.y),
+            self.x.max(other.x),
+        )
+    }
+
+    /// The six points sharing a face with this one.
+    pub fn neighbours_6(self) -> [Point3; 6] {
+        [
+            (-1, 0, 0),
+            (1, 0, 0),
+            (0, -1, 0),
+            (0, 1, 0),
+            (0, 0, -1),
+            (0, 0, 1),
+        ]
+        .map(|(z, y, x)| self + Point3::new(z, y, x))
+    }
+}
+
+macro_rules! impl_ops {
+    ($point:ident, $($c:ident),+) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($c: self.$c + other.$c),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($c: self.$c - other.$c),+ }
+            }
+        }
+
+        impl Mul<i32> for $point {
+            type Output = $point;
+
+            fn mul(self, n: i32) -> $point {
+                $point { $($c: self.$c * n),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($c: -self.$c),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_ops!(Point2, y, x);
+impl_ops!(Point3, z, y, x);
+
+impl From<(i32, i32)> for Point2 {
+    fn from((y, x): (i32, i32)) -> Point2 {
+        Point2::new(y, x)
+    }
+}
+
+impl From<Point2> for (i32, i32) {
+    fn from(p: Point2) -> (i32, i32) {
+        (p.y, p.x)
+    }
+}
+
+/// For `Grid::offset`.
+impl From<Point2> for (isize, isize) {
+    fn from(p: Point2) -> (isize, isize) {
+        (p.y as isize, p.x as isize)
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.y, self.x)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {}, {})", self.z, self.y, self.x)
+    }
+}
+
+/// The four directions in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The direction after `n` quarter turns, clockwise for positive `n`.
+    pub fn turn(self, n: i32) -> Direction {
+        Direction::ALL[(self as i32 + n).rem_euclid(4) as usize]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn(1)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn(-1)
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn(2)
+    }
+
+    pub const fn offset(self) -> Point2 {
+        match self {
+            Direction::Up => Point2::new(-1, 0),
+            Direction::Right => Point2::new(0, 1),
+            Direction::Down => Point2::new(1, 0),
+            Direction::Left => Point2::new(0, -1),
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_point2() {
+        let (a, b) = (Point2::new(1, 5), Point2::new(4, 2));
+        assert_eq!(a + b, Point2::new(5, 7));
+        assert_eq!(a - b, Point2::new(-3, 3));
+        assert_eq!(-a * 2, Point2::new(-2, -10));
+        assert_eq!(a.manhattan(b), 6);
+        assert_eq!(a.chebyshev(b), 3);
+        assert_eq!((a - b).signum(), Point2::new(-1, 1));
+        assert_eq!(a.min(b), Point2::new(1, 2));
+        assert_eq!(a.max(b), Point2::new(4, 5));
+
+        let mut c = a;
+        c += b;
+        c -= Point2::new(5, 5);
+        assert_eq!(<(i32, i32)>::from(c), (0, 2));
+        assert_eq!(Point2::ORIGIN.neighbours_4()[1], Point2::new(0, 1));
+        assert!(!Point2::ORIGIN.neighbours_8().contains(&Point2::ORIGIN));
+        assert_eq!(a.to_string(), "(1, 5)");
+    }
+
+    #[test]
+    pub fn test_point3() {
+        let (a, b) = (Point3::new(1, -2, 3), Point3::new(0, 2, 1));
+        assert_eq!(a + b, Point3::new(1, 0, 4));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.min(b), Point3::new(0, -2, 1));
+        assert_eq!(
+            Point3::ORIGIN
+                .neighbours_6()
+                .iter()
+                .map(|p| p.manhattan(Point3::ORIGIN))
+                .sum::<i32>(),
+            6
+        );
+    }
+
+    #[test]
+    pub fn test_direction() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn(-5), Direction::Down);
+        assert_eq!(Direction::Right.reverse(), Direction::Left);
+        assert_eq!(Direction::Down.offset(), Point2::new(1, 0));
+        assert!(Direction::Left.is_horizontal());
+        for d in Direction::ALL {
+            assert_eq!(d.offset() + d.reverse().offset(), Point2::ORIGIN);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::cell::Cell;
+use std::ops::Range;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::{self, ScopedJoinHandle};
+
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static IN_POOL: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Limits the number of threads, 0 uses one per available core.
+pub fn set_threads(n: usize) {
+    THREADS.store(n, Ordering::Relaxed);
+}
+
+pub fn threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    }
+}
+
+/// Work inside a pool thread stays on that thread, so nested fan-outs never exceed the limit.
+fn workers(jobs: usize) -> usize {
+    if IN_POOL.get() {
+        1
+    } else {
+        threads().min(jobs).max(1)
+    }
+}
+
+fn join<T>(handle: ScopedJoinHandle<T>) -> T {
+    handle.join().unwrap_or_else(|e| panic::resume_unwind(e))
+}
+
+/// Applies `f` to every item on the pool and returns the results in the order of `items`.
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = workers(items.len());
+    if workers == 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<(usize, R)> = thread::scope(|s| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                s.spawn(|| {
+                    IN_POOL.set(true);
+                    let mut done = Vec::new();
+                    loop {
+                        let i = next.fetch_add(1, Ordering::Relaxed);
+                        if i >= items.len() {
+                            return done;
+                        }
+                        done.push((i, f(&items[i])));
+                    }
+                })
+            })
+            .collect();
+        handles.into_iter().flat_map(join).collect()
+    });
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, r)| r).collect()
+}
+
+/// The result of the lowest index in `range` for which `f` returns one. Workers take `chunk`
+/// indices at a time and stop taking new ones past the best index found so far.
+pub fn find_first<R, F>(mut range: Range<usize>, chunk: usize, f: F) -> Option<R>
+where
+    R: Send,
+    F: Fn(usize) -> Option<R> + Sync,
+{
+    let chunk = chunk.max(1);
+    let chunks = range.len().div_ceil(chunk);
+    let workers = workers(chunks);
+    if workers == 1 {
+        return range.find_map(f);
+    }
+
+    let next = AtomicUsize::new(0);
+    let found = AtomicUsize::new(usize::MAX);
+    thread::scope(|s| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                s.spawn(|| {
+                    IN_POOL.set(true);
+                    loop {
+                        let c = next.fetch_add(1, Ordering::Relaxed);
+                        let start = range.start + c * chunk;
+                        if c >= chunks || start > found.load(Ordering::Relaxed) {
+                            return None;
+                        }
+                        let end = (start + chunk).min(range.end);
+                        if let Some((i, r)) = (start..end).find_map(|i| f(i).map(|r| (i, r))) {
+                            found.fetch_min(i, Ordering::Relaxed);
+                            return Some((i, r));
+                        }
+                    }
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .filter_map(join)
+            .min_by_key(|(i, _)| *i)
+            .map(|(_, r)| r)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_map() {
+        let items: Vec<u64> = (0..100).collect();
+        assert_eq!(
+            map(&items, |i| i * i),
+            items.iter().map(|i| i * i).collect::<Vec<u64>>()
+        );
+        assert_eq!(map(&[] as &[u64], |i| *i), []);
+
+        let nested = map(&[1, 2, 3], |n| {
+            map(&[10, 20], |m| n * m).iter().sum::<i32>()
+        });
+        assert_eq!(nested, [30, 60, 90]);
+        assert!(threads() >= 1);
+    }
+
+    #[test]
+    pub fn test_find_first() {
+        assert_eq!(
+            find_first(0..10_000, 7, |i| (i % 1000 == 999).then_some(i)),
+            Some(999)
+        );
+        assert_eq!(
+            find_first(5..50, 4, |i| (i >= 6).then_some(i * 2)),
+            Some(12)
+        );
+        assert_eq!(find_first(0..100, 3, |_| None::<usize>), None);
+        assert_eq!(find_first(0..0, 3, Some), None);
+    }
+}
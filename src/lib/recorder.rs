@@ -0,0 +1,286 @@
+use crate::animation::{Cell, Frames, Viewport};
+use crate::gif::{self, Image, Rgb};
+use std::fs;
+use std::io;
+use std::iter;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const PALETTE_BITS: u8 = 3;
+
+/// Background, empty, wall, actor and four marker colours, markers pick theirs by character.
+pub const PALETTE: [Rgb; 1 << PALETTE_BITS] = [
+    [0, 0, 0],
+    [40, 40, 48],
+    [170, 170, 170],
+    [230, 60, 50],
+    [70, 140, 230],
+    [230, 200, 60],
+    [80, 190, 90],
+    [170, 90, 200],
+];
+
+fn palette_index(cell: Cell) -> u8 {
+    match cell {
+        Cell::Blank => 0,
+        Cell::Empty => 1,
+        Cell::Wall => 2,
+        Cell::Actor => 3,
+        Cell::Marker(c) => 4 + (c as u32 % 4) as u8,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordFormat {
+    Ppm,
+    Pgm,
+    Gif,
+}
+
+impl RecordFormat {
+    pub fn named(name: &str) -> Option<RecordFormat> {
+        match name {
+            "ppm" => Some(RecordFormat::Ppm),
+            "pgm" => Some(RecordFormat::Pgm),
+            "gif" => Some(RecordFormat::Gif),
+            _ => None,
+        }
+    }
+
+    /// A single GIF for paths ending in `.gif`, otherwise a directory of PPM frames.
+    pub fn from_path(path: &str) -> RecordFormat {
+        if path.ends_with(".gif") {
+            RecordFormat::Gif
+        } else {
+            RecordFormat::Ppm
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecorderConfig {
+    pub format: RecordFormat,
+    /// Pixels per side of a cell.
+    pub scale: usize,
+    /// Keeps only every nth frame.
+    pub every: usize,
+    /// Stops the simulation after this many recorded frames.
+    pub limit: Option<usize>,
+    pub delay: Duration,
+}
+
+impl Default for RecorderConfig {
+    fn default() -> Self {
+        RecorderConfig {
+            format: RecordFormat::Gif,
+            scale: 4,
+            every: 1,
+            limit: None,
+            delay: Duration::from_millis(100),
+        }
+    }
+}
+
+/// Writes the frames of a simulation to image files instead of the terminal.
+pub struct Recorder {
+    config: RecorderConfig,
+    path: PathBuf,
+    seen: usize,
+    recorded: usize,
+    images: Vec<(Viewport, Image)>,
+    error: Option<io::Error>,
+}
+
+impl Recorder {
+    /// PPM and PGM frames go into the directory `path`, which is created if needed.
+    pub fn new<P: AsRef<Path>>(path: P, config: RecorderConfig) -> io::Result<Recorder> {
+        let path = path.as_ref().to_path_buf();
+        if config.format != RecordFormat::Gif {
+            fs::create_dir_all(&path)?;
+        }
+        Ok(Recorder {
+            config,
+            path,
+            seen: 0,
+            recorded: 0,
+            images: Vec::new(),
+            error: None,
+        })
+    }
+
+    pub fn recorded(&self) -> usize {
+        self.recorded
+    }
+
+    /// Writes the GIF, if that is the format, and returns the number of recorded frames.
+    pub fn finish(self) -> io::Result<usize> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+        if self.config.format == RecordFormat::Gif {
+            fs::write(&self.path, self.gif())?;
+        }
+        Ok(self.recorded)
+    }
+
+    fn gif(&self) -> Vec<u8> {
+        let screen = Viewport::bounding(
+            self.images
+                .iter()
+                .flat_map(|(v, _)| [(v.y_min, v.x_min), (v.y_max, v.x_max)]),
+        )
+        .unwrap_or(Viewport::new(0, 0, 0, 0));
+        let scale = self.config.scale as i32;
+        let images: Vec<Image> = self
+            .images
+            .iter()
+            .map(|(v, image)| {
+                let mut image = image.clone();
+                image.left = ((v.x_min - screen.x_min) * scale) as u16;
+                image.top = ((v.y_min - screen.y_min) * scale) as u16;
+                image
+            })
+            .collect();
+        let (width, height) = pixel_size(&screen, self.config.scale);
+        let delay_cs = (self.config.delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+        gif::encode(width as u16, height as u16, &PALETTE, delay_cs, &images)
+    }
+
+    fn record(&mut self, view: &Viewport, pixels: Vec<u8>) -> io::Result<()> {
+        let (width, height) = pixel_size(view, self.config.scale);
+        let (extension, header, channels) = match self.config.format {
+            RecordFormat::Ppm => ("ppm", "P6", 3),
+            RecordFormat::Pgm => ("pgm", "P5", 1),
+            RecordFormat::Gif => {
+                if width > u16::MAX as usize || height > u16::MAX as usize {
+                    return Err(io::Error::other("frame is too large for a GIF"));
+                }
+                let image = Image::new(0, 0, width as u16, height as u16, &pixels, PALETTE_BITS);
+                self.images.push((*view, image));
+                return Ok(());
+            }
+        };
+        let mut file = format!("{}\n{} {}\n255\n", header, width, height).into_bytes();
+        file.reserve(pixels.len() * channels);
+        for p in pixels {
+            let [r, g, b] = PALETTE[p as usize];
+            match self.config.format {
+                RecordFormat::Pgm => file.push(grey([r, g, b])),
+                _ => file.extend([r, g, b]),
+            }
+        }
+        let name = format!("frame_{:05}.{}", self.recorded, extension);
+        fs::write(self.path.join(name), file)
+    }
+}
+
+impl Frames for Recorder {
+    fn frame(
+        &mut self,
+        bounds: &Viewport,
+        _focus: (i32, i32),
+        _caption: &str,
+        cell: &mut dyn FnMut(i32, i32) -> Cell,
+    ) -> bool {
+        self.seen += 1;
+        if !(self.seen - 1).is_multiple_of(self.config.every.max(1)) {
+            return true;
+        }
+        if let Err(e) = self.record(bounds, rasterize(bounds, self.config.scale, cell)) {
+            self.error = Some(e);
+            return false;
+        }
+        self.recorded += 1;
+        self.config.limit.is_none_or(|limit| self.recorded < limit)
+    }
+}
+
+fn pixel_size(view: &Viewport, scale: usize) -> (usize, usize) {
+    (
+        (view.x_max - view.x_min + 1) as usize * scale,
+        (view.y_max - view.y_min + 1) as usize * scale,
+    )
+}
+
+/// Palette indices of `view` with every cell drawn as a `scale` sized square.
+pub fn rasterize(view: &Viewport, scale: usize, cell: &mut dyn FnMut(i32, i32) -> Cell) -> Vec<u8> {
+    let (width, height) = pixel_size(view, scale);
+    let mut pixels = Vec::with_capacity(width * height);
+    for y in view.y_min..=view.y_max {
+        let row: Vec<u8> = (view.x_min..=view.x_max)
+            .flat_map(|x| iter::repeat_n(palette_index(cell(y, x)), scale))
+            .collect();
+        for _ in 0..scale {
+            pixels.extend(&row);
+        }
+    }
+    pixels
+}
+
+fn grey([r, g, b]: Rgb) -> u8 {
+    ((r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn checker(y: i32, x: i32) -> Cell {
+        if (y + x) % 2 == 0 {
+            Cell::Wall
+        } else {
+            Cell::Empty
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc2022_{}_{}", name, std::process::id()))
+    }
+
+    #[test]
+    pub fn test_rasterize() {
+        let pixels = rasterize(&Viewport::new(0, 0, 1, 1), 2, &mut checker);
+        assert_eq!(pixels, [2, 2, 1, 1, 2, 2, 1, 1, 1, 1, 2, 2, 1, 1, 2, 2]);
+        assert_eq!(palette_index(Cell::Marker('o')), 7);
+        assert_eq!(grey([255, 255, 255]), 255);
+    }
+
+    #[test]
+    pub fn test_record_pgm() {
+        let path = temp_path("pgm");
+        let config = RecorderConfig {
+            format: RecordFormat::Pgm,
+            scale: 1,
+            every: 2,
+            limit: Some(2),
+            ..RecorderConfig::default()
+        };
+        let mut recorder = Recorder::new(&path, config).unwrap();
+        let view = Viewport::new(0, 0, 0, 2);
+        let frames: Vec<bool> = (0..4)
+            .map(|_| recorder.frame(&view, (0, 0), "", &mut checker))
+            .collect();
+        assert_eq!(frames, [true, true, false, true]);
+        assert_eq!(recorder.finish().unwrap(), 2);
+
+        let file = fs::read(path.join("frame_00001.pgm")).unwrap();
+        assert_eq!(file, b"P5\n3 1\n255\n\xaa\x28\xaa");
+        assert!(!path.join("frame_00002.pgm").exists());
+        fs::remove_dir_all(path).unwrap();
+    }
+
+    #[test]
+    pub fn test_record_gif() {
+        let path = temp_path("frames.gif");
+        let mut recorder = Recorder::new(&path, RecorderConfig::default()).unwrap();
+        recorder.frame(&Viewport::new(0, 0, 1, 1), (0, 0), "", &mut checker);
+        recorder.frame(&Viewport::new(-1, 0, 1, 3), (0, 0), "", &mut checker);
+        assert_eq!(recorder.recorded(), 2);
+        assert_eq!(recorder.finish().unwrap(), 2);
+
+        let file = fs::read(&path).unwrap();
+        assert!(file.starts_with(b"GIF89a\x10\x00\x0c\x00"));
+        fs::remove_file(path).unwrap();
+    }
+}
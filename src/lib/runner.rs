@@ -0,0 +1,420 @@
+use crate::animation::Frames;
+use crate::bench::{self, BenchConfig, DayBench};
+use crate::day::Day;
+use crate::differential::{self, Comparison};
+use crate::parse::ParseError;
+use crate::pool;
+use crate::random::Rng;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub answer: String,
+    pub time: Duration,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub day: u8,
+    pub parse_time: Duration,
+    pub part_1: PartResult,
+    pub part_2: Option<PartResult>,
+}
+
+impl DayResult {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.part_1.time + self.part_2.as_ref().map_or(Duration::ZERO, |p| p.time)
+    }
+}
+
+pub fn timed<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
+
+pub trait Solver: Sync {
+    fn day(&self) -> u8;
+    fn input(&self, name: &str) -> Option<&'static str>;
+    fn animated(&self) -> bool;
+    /// The input `Day::generate` makes from `seed`.
+    fn generate(&self, seed: u64, size: usize) -> String;
+    fn solve(&self, file: &str, input_name: &str) -> Result<DayResult, ParseError>;
+    /// The answers next to the ones of the reference solvers, for the parts that have one.
+    fn compare(&self, file: &str, input_name: &str) -> Result<Vec<Comparison>, ParseError>;
+    fn bench(
+        &self,
+        file: &str,
+        input_name: &str,
+        config: &BenchConfig,
+    ) -> Result<DayBench, ParseError>;
+    fn animate(
+        &self,
+        file: &str,
+        input_name: &str,
+        frames: &mut dyn Frames,
+    ) -> Result<(), ParseError>;
+}
+
+impl<D: Day + Sync> Solver for D {
+    fn day(&self) -> u8 {
+        D::DAY
+    }
+
+    fn input(&self, name: &str) -> Option<&'static str> {
+        D::input(name)
+    }
+
+    fn animated(&self) -> bool {
+        D::ANIMATED
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> String {
+        D::generate(&mut Rng::new(seed), size)
+    }
+
+    fn solve(&self, file: &str, input_name: &str) -> Result<DayResult, ParseError> {
+        solve::<D>(file, input_name)
+    }
+
+    fn compare(&self, file: &str, input_name: &str) -> Result<Vec<Comparison>, ParseError> {
+        differential::compare::<D>(file, input_name)
+    }
+
+    fn bench(
+        &self,
+        file: &str,
+        input_name: &str,
+        config: &BenchConfig,
+    ) -> Result<DayBench, ParseError> {
+        bench::bench::<D>(file, input_name, config)
+    }
+
+    fn animate(
+        &self,
+        file: &str,
+        input_name: &str,
+        frames: &mut dyn Frames,
+    ) -> Result<(), ParseError> {
+        D::animate(&D::parse(file)?, &D::params(input_name), frames);
+        Ok(())
+    }
+}
+
+pub fn solve<D: Day>(file: &str, input_name: &str) -> Result<DayResult, ParseError> {
+    let params = D::params(input_name);
+    let (input, parse_time) = timed(|| D::parse(file));
+    let input = input?;
+    Ok(DayResult {
+        day: D::DAY,
+        parse_time,
+        part_1: solve_part(|| D::part_1(&input, &params)),
+        part_2: D::has_part_2().then(|| solve_part(|| D::part_2(&input, &params))),
+    })
+}
+
+/// Solves every input on the pool, results keep the order of `inputs`.
+pub fn solve_all(
+    inputs: &[(&dyn Solver, String)],
+    input_name: &str,
+) -> Vec<Result<DayResult, ParseError>> {
+    pool::map(inputs, |(solver, file)| solver.solve(file, input_name))
+}
+
+fn solve_part<A: Display, F: FnOnce() -> A>(part: F) -> PartResult {
+    let (answer, time) = timed(part);
+    PartResult {
+        answer: answer.to_string(),
+        time,
+    }
+}
+
+pub fn format_table(results: &[DayResult]) -> String {
+    let answer_cell = |part: Option<&PartResult>| match part {
+        Some(p) if is_multi_line(&p.answer) => "(see below)".to_string(),
+        Some(p) => p.answer.clone(),
+        None => "-".to_string(),
+    };
+    let time_cell = |part: Option<&PartResult>| match part {
+        Some(p) => format_duration(p.time),
+        None => "-".to_string(),
+    };
+
+    let mut rows = vec![[
+        "Day".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+        "Parse".to_string(),
+        "Part 1".to_string(),
+        "Part 2".to_string(),
+        "Total".to_string(),
+    ]];
+    for r in results {
+        rows.push([
+            format!("{:02}", r.day),
+            answer_cell(Some(&r.part_1)),
+            answer_cell(r.part_2.as_ref()),
+            format_duration(r.parse_time),
+            time_cell(Some(&r.part_1)),
+            time_cell(r.part_2.as_ref()),
+            format_duration(r.total_time()),
+        ]);
+    }
+    let total = results.iter().map(|r| r.total_time()).sum();
+    rows.push([
+        "All".to_string(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        format_duration(total),
+    ]);
+
+    let mut table = align_columns(&rows);
+    for r in results {
+        for (part, p) in [(1, Some(&r.part_1)), (2, r.part_2.as_ref())] {
+            if let Some(p) = p.filter(|p| is_multi_line(&p.answer)) {
+                table.push_str(&format!("\nDay {:02} part {}:\n", r.day, part));
+                table.push_str(p.answer.trim_matches('\n'));
+                table.push('\n');
+            }
+        }
+    }
+
+    table
+}
+
+/// Pads every cell to the width of its column, separating columns by two spaces.
+pub fn align_columns<R: AsRef<[String]>>(rows: &[R]) -> String {
+    let mut widths = Vec::new();
+    for row in rows {
+        for (i, cell) in row.as_ref().iter().enumerate() {
+            if widths.len() <= i {
+                widths.push(0);
+            }
+            widths[i] = widths[i].max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for row in rows {
+        let line: Vec<String> = row
+            .as_ref()
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        table.push_str(line.join("  ").trim_end());
+        table.push('\n');
+    }
+    table
+}
+
+/// One object per solved part, answers are always strings so multi-line answers keep their line breaks.
+pub fn format_json(results: &[DayResult]) -> String {
+    let mut parts = Vec::new();
+    for r in results {
+        for (part, p) in [(1, Some(&r.part_1)), (2, r.part_2.as_ref())] {
+            if let Some(p) = p {
+                parts.push(format!(
+                    "  {{\"day\": {}, \"part\": {}, \"answer\": {}, \"parse_time_ns\": {}, \"solve_time_ns\": {}}}",
+                    r.day,
+                    part,
+                    json_string(&p.answer),
+                    r.parse_time.as_nanos(),
+                    p.time.as_nanos()
+                ));
+            }
+        }
+    }
+    if parts.is_empty() {
+        "[]\n".to_string()
+    } else {
+        format!("[\n{}\n]\n", parts.join(",\n"))
+    }
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut json = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+fn is_multi_line(answer: &str) -> bool {
+    answer.trim_matches('\n').contains('\n')
+}
+
+pub fn format_duration(d: Duration) -> String {
+    format!("{:.1?}", d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day::{GENERATED, MAIN, TEST};
+    use crate::parse::Source;
+
+    fn result(day: u8, part_1: &str, part_2: Option<&str>) -> DayResult {
+        DayResult {
+            day,
+            parse_time: Duration::from_micros(10),
+            part_1: PartResult {
+                answer: part_1.into(),
+                time: Duration::from_micros(20),
+            },
+            part_2: part_2.map(|answer| PartResult {
+                answer: answer.into(),
+                time: Duration::from_micros(30),
+            }),
+        }
+    }
+
+    struct Lengths;
+
+    impl Day for Lengths {
+        const DAY: u8 = 3;
+        const MAIN_INPUT: &'static str = "ab\nc";
+        const TEST_INPUT: &'static str = "abc";
+
+        type Input = Vec<usize>;
+        type Params = ();
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        fn parse(file: &str) -> Result<Vec<usize>, ParseError> {
+            let source = Source::new(file);
+            source
+                .lines()
+                .map(|l| match l.find(|c: char| !c.is_ascii_lowercase()) {
+                    Some(i) => Err(source.error(&l[i..], "a lowercase letter")),
+                    None => Ok(l.len()),
+                })
+                .collect()
+        }
+
+        fn part_1(input: &Vec<usize>, _params: &()) -> usize {
+            input.iter().sum()
+        }
+
+        fn part_2(input: &Vec<usize>, _params: &()) -> String {
+            format!("{:?}", input)
+        }
+
+        fn generate(rng: &mut Rng, size: usize) -> String {
+            let lengths = (0..size).map(|_| rng.below(5));
+            lengths.map(|l| "a".repeat(l) + "\n").collect()
+        }
+    }
+
+    #[test]
+    pub fn test_solve() {
+        let r = Lengths.solve(Lengths.input(MAIN).unwrap(), MAIN).unwrap();
+        assert_eq!(r.day, 3);
+        assert_eq!(r.part_1.answer, "3");
+        assert_eq!(r.part_2.unwrap().answer, "[2, 1]");
+    }
+
+    #[test]
+    pub fn test_solve_test_input() {
+        let r = Lengths.solve(Lengths.input(TEST).unwrap(), TEST).unwrap();
+        assert_eq!(r.part_1.answer, "3");
+        assert_eq!(r.part_2.unwrap().answer, "[3]");
+    }
+
+    #[test]
+    pub fn test_solve_parse_error() {
+        let error = Lengths.solve("ab\nc1", MAIN).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+    }
+
+    #[test]
+    pub fn test_generate() {
+        let file = Lengths.generate(4, 10);
+        assert_eq!(file, Lengths.generate(4, 10));
+        assert_eq!(file.lines().count(), 10);
+        assert!(Lengths.solve(&file, GENERATED).is_ok());
+    }
+
+    #[test]
+    pub fn test_solve_all() {
+        let inputs: [(&dyn Solver, String); 3] = [
+            (&Lengths, "a\nbc".into()),
+            (&Lengths, "A".into()),
+            (&Lengths, "abcd".into()),
+        ];
+        let results = solve_all(&inputs, MAIN);
+        assert_eq!(results[0].as_ref().unwrap().part_1.answer, "3");
+        assert!(results[1].is_err());
+        assert_eq!(
+            results[2].as_ref().unwrap().part_2.as_ref().unwrap().answer,
+            "[4]"
+        );
+    }
+
+    #[test]
+    pub fn test_format_json() {
+        let json = format_json(&[
+            result(10, "15360", Some("\n#.\n.#\n")),
+            result(25, "2=-1=0", None),
+        ]);
+        assert_eq!(
+            json,
+            "[\n\
+             \x20 {\"day\": 10, \"part\": 1, \"answer\": \"15360\", \"parse_time_ns\": 10000, \"solve_time_ns\": 20000},\n\
+             \x20 {\"day\": 10, \"part\": 2, \"answer\": \"\\n#.\\n.#\\n\", \"parse_time_ns\": 10000, \"solve_time_ns\": 30000},\n\
+             \x20 {\"day\": 25, \"part\": 1, \"answer\": \"2=-1=0\", \"parse_time_ns\": 10000, \"solve_time_ns\": 20000}\n\
+             ]\n"
+        );
+        assert_eq!(format_json(&[]), "[]\n");
+    }
+
+    #[test]
+    pub fn test_json_string() {
+        assert_eq!(json_string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(json_string("\t\r\u{1}"), "\"\\t\\r\\u0001\"");
+    }
+
+    #[test]
+    pub fn test_format_table() {
+        let table = format_table(&[
+            result(1, "68442", Some("204837")),
+            result(10, "15360", Some("\n#..\n.#.\n")),
+            result(25, "2=-1=0", None),
+        ]);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(
+            lines[0],
+            "Day  Part 1  Part 2       Parse   Part 1  Part 2  Total"
+        );
+        assert_eq!(
+            lines[1],
+            "01   68442   204837       10.0µs  20.0µs  30.0µs  60.0µs"
+        );
+        assert_eq!(
+            lines[2],
+            "10   15360   (see below)  10.0µs  20.0µs  30.0µs  60.0µs"
+        );
+        assert_eq!(
+            lines[3],
+            "25   2=-1=0  -            10.0µs  20.0µs  -       30.0µs"
+        );
+        assert_eq!(
+            lines[4],
+            "All                                               150.0µs"
+        );
+        assert_eq!(&lines[5..], ["", "Day 10 part 2:", "#..", ".#."]);
+    }
+}
@@ -0,0 +1,305 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A graph the searches can walk. `Cost::default()` is the cost of standing still.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+    type Cost: Copy + Ord + Add<Output = Self::Cost> + Default;
+
+    /// The nodes reachable in one step from `node`, with the cost of that step.
+    fn neighbours(&self, node: &Self::Node) -> Vec<(Self::Node, Self::Cost)>;
+
+    /// A lower bound for the cost from `node` to the nearest goal, used by `astar`.
+    fn heuristic(&self, _node: &Self::Node) -> Self::Cost {
+        Self::Cost::default()
+    }
+}
+
+/// Everything a search reached, how it got there, and the goal it stopped at, if any.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    goal: Option<N>,
+    reached: HashMap<N, (Option<N>, C)>,
+    visited: usize,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// The cost from the nearest start to the goal.
+    pub fn cost(&self) -> Option<C> {
+        self.cost_to(self.goal.as_ref()?)
+    }
+
+    /// The nodes from a start to the goal, both included.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    pub fn cost_to(&self, node: &N) -> Option<C> {
+        self.reached.get(node).map(|(_, cost)| *cost)
+    }
+
+    /// The node `node` was reached from, `None` for starts and unreached nodes.
+    pub fn parent(&self, node: &N) -> Option<&N> {
+        self.reached.get(node)?.0.as_ref()
+    }
+
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.reached.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parent(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The nodes reached so far with their cost. Without a goal these are all reachable nodes.
+    pub fn reached(&self) -> impl Iterator<Item = (&N, C)> {
+        self.reached.iter().map(|(node, (_, cost))| (node, *cost))
+    }
+
+    /// The number of nodes whose neighbours were expanded.
+    pub fn visited(&self) -> usize {
+        self.visited
+    }
+}
+
+type SearchOf<G> = Search<<G as Graph>::Node, <G as Graph>::Cost>;
+
+/// Breadth-first search from all `starts` at once, stopping at the first node `is_goal` accepts.
+/// Only finds the cheapest path if every step costs the same.
+pub fn bfs<G, S, F>(graph: &G, starts: S, mut is_goal: F) -> SearchOf<G>
+where
+    G: Graph,
+    S: IntoIterator<Item = G::Node>,
+    F: FnMut(&G::Node) -> bool,
+{
+    let mut search = Search {
+        goal: None,
+        reached: HashMap::new(),
+        visited: 0,
+    };
+    let mut open = VecDeque::new();
+    for start in starts {
+        if !search.reached.contains_key(&start) {
+            search
+                .reached
+                .insert(start.clone(), (None, G::Cost::default()));
+            open.push_back((start, G::Cost::default()));
+        }
+    }
+    while let Some((node, cost)) = open.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        search.visited += 1;
+        for (next, step) in graph.neighbours(&node) {
+            if !search.reached.contains_key(&next) {
+                search
+                    .reached
+                    .insert(next.clone(), (Some(node.clone()), cost + step));
+                open.push_back((next, cost + step));
+            }
+        }
+    }
+    search
+}
+
+/// Cheapest-first search from all `starts` at once, stopping at the first node `is_goal` accepts.
+pub fn dijkstra<G, S, F>(graph: &G, starts: S, is_goal: F) -> SearchOf<G>
+where
+    G: Graph,
+    S: IntoIterator<Item = G::Node>,
+    F: FnMut(&G::Node) -> bool,
+{
+    best_first(graph, starts, is_goal, |_| G::Cost::default())
+}
+
+/// Like `dijkstra`, but expands nodes in the order of their cost plus `Graph::heuristic`. The
+/// heuristic must never overestimate, or the path found may not be the cheapest.
+pub fn astar<G, S, F>(graph: &G, starts: S, is_goal: F) -> SearchOf<G>
+where
+    G: Graph,
+    S: IntoIterator<Item = G::Node>,
+    F: FnMut(&G::Node) -> bool,
+{
+    best_first(graph, starts, is_goal, |node| graph.heuristic(node))
+}
+
+/// A node waiting in the open list. Equal priorities come out in the order they went in.
+struct Open<N, C> {
+    key: Reverse<(C, usize)>,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Open<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<N, C: Ord> Eq for Open<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Open<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Open<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+fn best_first<G, S, F, H>(graph: &G, starts: S, mut is_goal: F, heuristic: H) -> SearchOf<G>
+where
+    G: Graph,
+    S: IntoIterator<Item = G::Node>,
+    F: FnMut(&G::Node) -> bool,
+    H: Fn(&G::Node) -> G::Cost,
+{
+    let mut search = Search {
+        goal: None,
+        reached: HashMap::new(),
+        visited: 0,
+    };
+    let mut closed = HashSet::new();
+    let mut open = BinaryHeap::new();
+    let mut pushed = 0;
+    let mut push = |open: &mut BinaryHeap<_>, node: G::Node, cost: G::Cost| {
+        pushed += 1;
+        open.push(Open {
+            key: Reverse((cost + heuristic(&node), pushed)),
+            cost,
+            node,
+        });
+    };
+
+    for start in starts {
+        if !search.reached.contains_key(&start) {
+            search
+                .reached
+                .insert(start.clone(), (None, G::Cost::default()));
+            push(&mut open, start, G::Cost::default());
+        }
+    }
+    while let Some(Open { cost, node, .. }) = open.pop() {
+        if !closed.insert(node.clone()) {
+            continue;
+        }
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        search.visited += 1;
+        for (next, step) in graph.neighbours(&node) {
+            let next_cost = cost + step;
+            if closed.contains(&next)
+                || search
+                    .reached
+                    .get(&next)
+                    .is_some_and(|(_, known)| *known <= next_cost)
+            {
+                continue;
+            }
+            search
+                .reached
+                .insert(next.clone(), (Some(node.clone()), next_cost));
+            push(&mut open, next, next_cost);
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A row of rooms where walking right costs 1 and a teleport from 0 to 4 costs `teleport`.
+    struct Rooms {
+        len: u32,
+        teleport: u32,
+    }
+
+    impl Graph for Rooms {
+        type Node = u32;
+        type Cost = u32;
+
+        fn neighbours(&self, node: &u32) -> Vec<(u32, u32)> {
+            let mut next = Vec::new();
+            if *node + 1 < self.len {
+                next.push((node + 1, 1));
+            }
+            if *node == 0 && self.len > 4 {
+                next.push((4, self.teleport));
+            }
+            next
+        }
+
+        fn heuristic(&self, node: &u32) -> u32 {
+            (self.len - 1 - node).min(self.teleport + 1)
+        }
+    }
+
+    #[test]
+    pub fn test_bfs() {
+        let rooms = Rooms {
+            len: 6,
+            teleport: 10,
+        };
+        let search = bfs(&rooms, [0], |n| *n == 5);
+        assert_eq!(search.path(), Some(vec![0, 4, 5]));
+        assert_eq!(search.cost(), Some(11));
+        assert_eq!(search.parent(&4), Some(&0));
+
+        let search = bfs(&rooms, [2, 3], |n| *n == 5);
+        assert_eq!(search.path(), Some(vec![3, 4, 5]));
+        assert_eq!(bfs(&rooms, [3], |n| *n == 0).goal(), None);
+        assert_eq!(bfs(&rooms, [3], |n| *n == 3).path(), Some(vec![3]));
+    }
+
+    #[test]
+    pub fn test_dijkstra() {
+        let rooms = Rooms {
+            len: 6,
+            teleport: 10,
+        };
+        let search = dijkstra(&rooms, [0], |n| *n == 5);
+        assert_eq!(search.path(), Some(vec![0, 1, 2, 3, 4, 5]));
+        assert_eq!(search.cost(), Some(5));
+
+        let rooms = Rooms {
+            len: 6,
+            teleport: 2,
+        };
+        let search = dijkstra(&rooms, [0], |_| false);
+        assert_eq!(search.cost(), None);
+        assert_eq!(search.cost_to(&4), Some(2));
+        assert_eq!(search.path_to(&5), Some(vec![0, 4, 5]));
+        assert_eq!(search.reached().count(), 6);
+        assert_eq!(search.visited(), 6);
+    }
+
+    #[test]
+    pub fn test_astar() {
+        let rooms = Rooms {
+            len: 6,
+            teleport: 2,
+        };
+        let search = astar(&rooms, [0], |n| *n == 5);
+        assert_eq!(search.path(), Some(vec![0, 4, 5]));
+        assert_eq!(search.cost(), Some(3));
+        assert_eq!(search.visited(), 2);
+        assert_eq!(dijkstra(&rooms, [0], |n| *n == 5).visited(), 4);
+        assert_eq!(astar(&rooms, [5, 0], |n| *n == 5).cost(), Some(0));
+    }
+}